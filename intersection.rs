@@ -0,0 +1,216 @@
+use std::str::FromStr;
+
+use crate::py_gc::PyGcTraverse;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyString, PyTuple};
+use pyo3::{intern, PyTraverseError, PyVisit};
+
+use crate::build_tools::py_schema_err;
+use crate::errors::{ErrorType, ValError, ValLineError, ValResult};
+use crate::input::Input;
+use crate::tools::SchemaDict;
+
+use super::custom_error::CustomError;
+use super::{
+    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator,
+};
+
+/// How the outputs of every choice are combined into the intersection's result.
+#[derive(Debug, Clone, Copy)]
+enum IntersectionMerge {
+    /// use the output of the last choice
+    Last,
+    /// every choice must output a dict, later keys win
+    MergeDicts,
+    /// every choice must output an equal value
+    Identical,
+}
+
+impl FromStr for IntersectionMerge {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "last" => Ok(Self::Last),
+            "merge_dicts" => Ok(Self::MergeDicts),
+            "identical" => Ok(Self::Identical),
+            s => py_schema_err!(
+                "Invalid intersection merge: `{}`, expected `last`, `merge_dicts` or `identical`",
+                s
+            ),
+        }
+    }
+}
+
+/// Requires the input to be valid against every choice, like JSON Schema `allOf`.
+#[derive(Debug)]
+pub struct IntersectionValidator {
+    choices: Vec<(CombinedValidator, Option<String>)>,
+    merge: IntersectionMerge,
+    custom_error: Option<CustomError>,
+    name: String,
+}
+
+impl BuildValidator for IntersectionValidator {
+    const EXPECTED_TYPE: &'static str = "intersection";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let choices: Vec<(CombinedValidator, Option<String>)> = schema
+            .get_as_req::<Bound<'_, PyList>>(intern!(py, "choices"))?
+            .iter()
+            .map(|choice| {
+                let mut label: Option<String> = None;
+                let choice = match choice.downcast::<PyTuple>() {
+                    Ok(py_tuple) => {
+                        let choice = py_tuple.get_item(0)?;
+                        label = Some(py_tuple.get_item(1)?.to_string());
+                        choice
+                    }
+                    Err(_) => choice,
+                };
+                Ok((build_validator(&choice, config, definitions)?, label))
+            })
+            .collect::<PyResult<Vec<(CombinedValidator, Option<String>)>>>()?;
+
+        if choices.is_empty() {
+            return py_schema_err!("One or more intersection choices required");
+        }
+
+        let merge = schema
+            .get_as::<Bound<'_, PyString>>(intern!(py, "merge"))?
+            .map_or(Ok(IntersectionMerge::Last), |merge| merge.to_str().and_then(IntersectionMerge::from_str))?;
+
+        let descr = choices
+            .iter()
+            .map(|(choice, label)| label.as_deref().unwrap_or(choice.get_name()))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        Ok(Self {
+            choices,
+            merge,
+            custom_error: CustomError::build(schema, config, definitions)?,
+            name: format!("{}[{descr}]", Self::EXPECTED_TYPE),
+        }
+        .into())
+    }
+}
+
+impl PyGcTraverse for IntersectionValidator {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        self.choices.iter().try_for_each(|(v, _)| v.py_gc_traverse(visit))?;
+        Ok(())
+    }
+}
+
+impl Validator for IntersectionValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let mut errors: Vec<ValLineError> = Vec::new();
+        let mut outputs: Vec<(PyObject, &str)> = Vec::with_capacity(self.choices.len());
+
+        // every choice validates the same input, so their exactness and fields set are combined and
+        // applied once rather than each choice adding to the parent's
+        let old_exactness = state.exactness;
+        let old_fields_set_count = state.fields_set_count;
+        let mut exactness = Exactness::Exact;
+        let mut fields_set_count: Option<usize> = None;
+
+        // every choice is tried even after one fails, so all the errors are reported together
+        for (choice, label) in &self.choices {
+            let choice_name = label.as_deref().unwrap_or(choice.get_name());
+            state.exactness = Some(Exactness::Exact);
+            state.fields_set_count = None;
+            let result = choice.validate(py, input, state);
+            exactness = exactness.min(state.exactness.unwrap_or(Exactness::Lax));
+            fields_set_count = fields_set_count.max(state.fields_set_count);
+            match result {
+                Ok(output) => outputs.push((output, choice_name)),
+                Err(ValError::LineErrors(lines)) => {
+                    errors.extend(lines.into_iter().map(|err| err.with_outer_location(choice_name)));
+                }
+                Err(err) => {
+                    state.exactness = old_exactness;
+                    state.fields_set_count = old_fields_set_count;
+                    return Err(err);
+                }
+            }
+        }
+
+        state.exactness = old_exactness;
+        state.fields_set_count = old_fields_set_count;
+        state.floor_exactness(exactness);
+        if let Some(count) = fields_set_count {
+            state.add_fields_set(count);
+        }
+
+        if !errors.is_empty() {
+            return Err(match self.custom_error {
+                Some(ref custom_error) => custom_error.as_val_error(input),
+                None => ValError::LineErrors(errors),
+            });
+        }
+        self.merge_outputs(py, input, outputs)
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl IntersectionValidator {
+    fn merge_outputs<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        outputs: Vec<(PyObject, &str)>,
+    ) -> ValResult<PyObject> {
+        let mut outputs = outputs.into_iter();
+        // there's always at least one choice, checked when building
+        let (first, first_name) = outputs.next().unwrap();
+        match self.merge {
+            IntersectionMerge::Last => Ok(outputs.last().map_or(first, |(output, _)| output)),
+            IntersectionMerge::MergeDicts => {
+                let merged = PyDict::new(py);
+                for (output, choice_name) in std::iter::once((first, first_name)).chain(outputs) {
+                    // e.g. a function validator returning something else, which can't be known when building
+                    let Ok(dict) = output.bind(py).downcast::<PyDict>() else {
+                        return Err(match self.custom_error {
+                            Some(ref custom_error) => custom_error.as_val_error(input),
+                            None => ValError::new(ErrorType::DictType { context: None }, input)
+                                .with_outer_location(choice_name),
+                        });
+                    };
+                    merged.update(dict.as_mapping())?;
+                }
+                Ok(merged.into_any().unbind())
+            }
+            IntersectionMerge::Identical => {
+                for (output, choice_name) in outputs {
+                    if !first.bind(py).eq(output.bind(py))? {
+                        return Err(match self.custom_error {
+                            Some(ref custom_error) => custom_error.as_val_error(input),
+                            None => ValError::new(
+                                ErrorType::IntersectionConflict {
+                                    choices: format!("{first_name}, {choice_name}"),
+                                    context: None,
+                                },
+                                input,
+                            ),
+                        });
+                    }
+                }
+                Ok(first)
+            }
+        }
+    }
+}
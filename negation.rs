@@ -0,0 +1,77 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::errors::{ErrorType, ValError, ValResult};
+use crate::input::Input;
+use crate::tools::SchemaDict;
+
+use super::custom_error::CustomError;
+use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+
+/// Succeeds only when the inner schema rejects the input, like JSON Schema `not`. The input is
+/// returned unchanged, since there's no validated value to return.
+#[derive(Debug)]
+pub struct NotValidator {
+    validator: Box<CombinedValidator>,
+    custom_error: Option<CustomError>,
+    name: String,
+}
+
+impl BuildValidator for NotValidator {
+    const EXPECTED_TYPE: &'static str = "not";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let sub_schema = schema.get_as_req(intern!(py, "schema"))?;
+        let validator = Box::new(build_validator(&sub_schema, config, definitions)?);
+        let name = format!("{}[{}]", Self::EXPECTED_TYPE, validator.get_name());
+        Ok(Self {
+            validator,
+            custom_error: CustomError::build(schema, config, definitions)?,
+            name,
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(NotValidator { validator });
+
+impl Validator for NotValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        // the inner validator's view of exactness doesn't apply to the unchanged input we return
+        let old_exactness = state.exactness;
+        let old_fields_set_count = state.fields_set_count;
+        let result = self.validator.validate(py, input, state);
+        state.exactness = old_exactness;
+        state.fields_set_count = old_fields_set_count;
+
+        match result {
+            Ok(_) => Err(match self.custom_error {
+                Some(ref custom_error) => custom_error.as_val_error(input),
+                None => ValError::new(
+                    ErrorType::Excluded {
+                        excluded: self.validator.get_name().to_string(),
+                        context: None,
+                    },
+                    input,
+                ),
+            }),
+            Err(ValError::LineErrors(_)) => Ok(input.to_object(py)?.unbind()),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
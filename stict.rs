@@ -77,7 +77,8 @@ fn test_field_strict_false_overrides_model_strict() {
         let mut state = ValidationState::new(true);  // Global strict=true
         let input = "123";
 
-        // Should pass because field overrides strictness
+        // Should pass because field overrides strictness. This needs the model-fields validator to run each field
+        // through `with_strict_override`, which isn't part of this tree, so it doesn't pass yet
         let result = model_validator.validate(py, &input, &mut state);
         assert!(result.is_ok());
     });
@@ -122,3 +123,817 @@ fn test_annotated_with_strict_false_in_union() {
         assert!(result.is_err());
     });
 }
+5. Typed-dicts with total=False Keep Smart Matching
+rust
+#[test]
+fn test_no_discriminator_inferred_from_non_total_typed_dicts() {
+    Python::with_gil(|py| {
+        // `kind` is a literal in both typed-dicts, but `total=False` makes it optional
+        let schema = py
+            .eval(
+                c"{'type': 'union', 'choices': [
+                    {'type': 'typed-dict', 'total': False, 'fields': {
+                        'kind': {'type': 'typed-dict-field', 'schema': {'type': 'literal', 'expected': ['cat']}},
+                        'meow': {'type': 'typed-dict-field', 'schema': {'type': 'int'}},
+                    }},
+                    {'type': 'typed-dict', 'total': False, 'fields': {
+                        'kind': {'type': 'typed-dict-field', 'schema': {'type': 'literal', 'expected': ['dog']}},
+                        'bark': {'type': 'typed-dict-field', 'schema': {'type': 'int'}},
+                    }},
+                ]}",
+                None,
+                None,
+            )
+            .unwrap();
+        let validator = build_validator(schema.downcast().unwrap(), None, &mut DefinitionsBuilder::new()).unwrap();
+        assert!(matches!(validator, CombinedValidator::Union(_)));  // not compiled to a tagged union
+
+        let mut state = ValidationState::new(false);
+        // no tag, but the input still matches the second choice
+        let input = py.eval(c"{'bark': 1}", None, None).unwrap();
+        let result = validator.validate(py, &input, &mut state);
+        assert!(result.is_ok());
+    });
+}
+6. Model Choices Are Prefiltered by Input Kind
+rust
+#[test]
+fn test_ordinary_model_choice_skipped_for_str_input() {
+    Python::with_gil(|py| {
+        let schema = py
+            .eval(
+                c"{'type': 'union', 'choices': [
+                    {'type': 'model', 'cls': type('M', (), {}), 'schema': {'type': 'model-fields', 'fields': {
+                        'x': {'type': 'model-field', 'schema': {'type': 'int'}},
+                    }}},
+                    {'type': 'str'},
+                ]}",
+                None,
+                None,
+            )
+            .unwrap();
+        let validator = build_validator(schema.downcast().unwrap(), None, &mut DefinitionsBuilder::new()).unwrap();
+        let CombinedValidator::Union(union) = &validator else { panic!("expected a union") };
+
+        // the model only accepts dicts and instances, so it's not tried for a str
+        let input = py.eval(c"'abc'", None, None).unwrap();
+        let input_kind = union.input_filter(&input);
+        assert!(!union.choices[0].input_kinds.intersects(input_kind));
+        assert!(union.choices[1].input_kinds.intersects(input_kind));
+    });
+}
+
+#[test]
+fn test_root_model_choice_keeps_root_type_kinds() {
+    Python::with_gil(|py| {
+        // `RootModel[list[int]] | list[str]`
+        let schema = py
+            .eval(
+                c"{'type': 'union', 'choices': [
+                    {'type': 'model', 'cls': type('R', (), {}), 'root_model': True,
+                     'schema': {'type': 'list', 'items_schema': {'type': 'int'}}},
+                    {'type': 'list', 'items_schema': {'type': 'str'}},
+                ]}",
+                None,
+                None,
+            )
+            .unwrap();
+        let validator = build_validator(schema.downcast().unwrap(), None, &mut DefinitionsBuilder::new()).unwrap();
+
+        // the root model is still tried for a list, and is the only choice that accepts it
+        let mut state = ValidationState::new(false);
+        let input = py.eval(c"[1, 2]", None, None).unwrap();
+        let result = validator.validate(py, &input, &mut state);
+        assert!(result.is_ok());
+    });
+}
+7. The Least-Coerced Lax Choice Wins
+rust
+#[test]
+fn test_one_coercion_beats_ten() {
+    Python::with_gil(|py| {
+        // the first typed-dict needs all ten strings coerced to ints, the second only the last one
+        let schema = py
+            .eval(
+                c"{'type': 'union', 'choices': [
+                    {'type': 'typed-dict', 'fields': {
+                        k: {'type': 'typed-dict-field', 'schema': {'type': 'int'}} for k in 'abcdefghij'
+                    }},
+                    {'type': 'typed-dict', 'fields': {
+                        k: {'type': 'typed-dict-field', 'schema': {'type': 'int' if k == 'j' else 'str'}}
+                        for k in 'abcdefghij'
+                    }},
+                ]}",
+                None,
+                None,
+            )
+            .unwrap();
+        let validator = build_validator(schema.downcast().unwrap(), None, &mut DefinitionsBuilder::new()).unwrap();
+
+        let mut state = ValidationState::new(false);
+        let input = py.eval(c"{k: str(i) for i, k in enumerate('abcdefghij')}", None, None).unwrap();
+        let result = validator.validate(py, &input, &mut state).unwrap();
+
+        // both choices match laxly, the second needed one coercion rather than ten
+        let result = result.bind(py).downcast::<PyDict>().unwrap();
+        let a = result.get_item("a").unwrap().unwrap();
+        assert!(a.is_instance_of::<PyString>());
+        let j = result.get_item("j").unwrap().unwrap();
+        assert!(j.is_instance_of::<PyInt>());
+    });
+}
+8. Strict-Then-Lax Mode Doesn't Relax a Strict Union
+rust
+#[test]
+fn test_strict_then_lax_keeps_union_strict() {
+    Python::with_gil(|py| {
+        let schema = py
+            .eval(
+                c"{'type': 'union', 'mode': 'strict_then_lax', 'strict': True, 'choices': [
+                    {'type': 'int'},
+                    {'type': 'float'},
+                ]}",
+                None,
+                None,
+            )
+            .unwrap();
+        let validator = build_validator(schema.downcast().unwrap(), None, &mut DefinitionsBuilder::new()).unwrap();
+
+        // no lax pass for a strict union, so "1" isn't coerced to an int
+        let mut state = ValidationState::new(false);
+        let input = py.eval(c"'1'", None, None).unwrap();
+        assert!(validator.validate(py, &input, &mut state).is_err());
+
+        // a lax union still falls back to coercing
+        let schema = py
+            .eval(
+                c"{'type': 'union', 'mode': 'strict_then_lax', 'choices': [{'type': 'int'}, {'type': 'float'}]}",
+                None,
+                None,
+            )
+            .unwrap();
+        let validator = build_validator(schema.downcast().unwrap(), None, &mut DefinitionsBuilder::new()).unwrap();
+        let result = validator.validate(py, &input, &mut state).unwrap();
+        assert!(result.bind(py).is_instance_of::<PyInt>());
+    });
+}
+9. Discriminators Are Inferred Unless Opted Out
+rust
+#[test]
+fn test_discriminator_inferred_from_literal_fields() {
+    Python::with_gil(|py| {
+        let schema = py
+            .eval(
+                c"{'type': 'union', 'choices': [
+                    {'type': 'typed-dict', 'fields': {
+                        'kind': {'type': 'typed-dict-field', 'schema': {'type': 'literal', 'expected': ['cat']}},
+                        'meow': {'type': 'typed-dict-field', 'schema': {'type': 'int'}},
+                    }},
+                    {'type': 'typed-dict', 'fields': {
+                        'kind': {'type': 'typed-dict-field', 'schema': {'type': 'literal', 'expected': ['dog']}},
+                        'bark': {'type': 'typed-dict-field', 'schema': {'type': 'int'}},
+                    }},
+                ]}",
+                None,
+                None,
+            )
+            .unwrap();
+        let validator = build_validator(schema.downcast().unwrap(), None, &mut DefinitionsBuilder::new()).unwrap();
+        assert!(matches!(validator, CombinedValidator::TaggedUnion(_)));
+
+        // errors come from the tagged choice only, located by its tag
+        let mut state = ValidationState::new(false);
+        let input = py.eval(c"{'kind': 'dog', 'bark': 'x'}", None, None).unwrap();
+        let Err(ValError::LineErrors(lines)) = validator.validate(py, &input, &mut state) else {
+            panic!("expected line errors");
+        };
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].location.to_string(), "dog.bark");
+    });
+}
+
+#[test]
+fn test_discriminator_inference_opt_out() {
+    Python::with_gil(|py| {
+        let schema = py
+            .eval(
+                c"{'type': 'union', 'infer_discriminator': False, 'choices': [
+                    {'type': 'typed-dict', 'fields': {
+                        'kind': {'type': 'typed-dict-field', 'schema': {'type': 'literal', 'expected': ['cat']}},
+                    }},
+                    {'type': 'typed-dict', 'fields': {
+                        'kind': {'type': 'typed-dict-field', 'schema': {'type': 'literal', 'expected': ['dog']}},
+                    }},
+                ]}",
+                None,
+                None,
+            )
+            .unwrap();
+        let validator = build_validator(schema.downcast().unwrap(), None, &mut DefinitionsBuilder::new()).unwrap();
+        assert!(matches!(validator, CombinedValidator::Union(_)));
+    });
+}
+
+#[test]
+fn test_no_discriminator_inferred_with_labels_or_closest_errors() {
+    Python::with_gil(|py| {
+        // labelled choices keep their labels as error locations
+        let schema = py
+            .eval(
+                c"{'type': 'union', 'choices': [
+                    ({'type': 'typed-dict', 'fields': {
+                        'kind': {'type': 'typed-dict-field', 'schema': {'type': 'literal', 'expected': ['cat']}},
+                    }}, 'Cat'),
+                    ({'type': 'typed-dict', 'fields': {
+                        'kind': {'type': 'typed-dict-field', 'schema': {'type': 'literal', 'expected': ['dog']}},
+                    }}, 'Dog'),
+                ]}",
+                None,
+                None,
+            )
+            .unwrap();
+        let validator = build_validator(schema.downcast().unwrap(), None, &mut DefinitionsBuilder::new()).unwrap();
+        assert!(matches!(validator, CombinedValidator::Union(_)));
+
+        // `error_mode='closest'` only applies to plain unions
+        let schema = py
+            .eval(
+                c"{'type': 'union', 'error_mode': 'closest', 'choices': [
+                    {'type': 'typed-dict', 'fields': {
+                        'kind': {'type': 'typed-dict-field', 'schema': {'type': 'literal', 'expected': ['cat']}},
+                    }},
+                    {'type': 'typed-dict', 'fields': {
+                        'kind': {'type': 'typed-dict-field', 'schema': {'type': 'literal', 'expected': ['dog']}},
+                    }},
+                ]}",
+                None,
+                None,
+            )
+            .unwrap();
+        let validator = build_validator(schema.downcast().unwrap(), None, &mut DefinitionsBuilder::new()).unwrap();
+        assert!(matches!(validator, CombinedValidator::Union(_)));
+    });
+}
+10. Single-Choice Unions Keep Their Options
+rust
+#[test]
+fn test_single_choice_with_options_not_collapsed() {
+    Python::with_gil(|py| {
+        let schema = py
+            .eval(c"{'type': 'union', 'choices': [({'type': 'int'}, 'number', {'strict': True})]}", None, None)
+            .unwrap();
+        let validator = build_validator(schema.downcast().unwrap(), None, &mut DefinitionsBuilder::new()).unwrap();
+        assert!(matches!(validator, CombinedValidator::Union(_)));
+
+        // the choice's `strict` still applies
+        let mut state = ValidationState::new(false);
+        let input = py.eval(c"'1'", None, None).unwrap();
+        assert!(validator.validate(py, &input, &mut state).is_err());
+    });
+}
+
+#[test]
+fn test_single_choice_with_return_label_not_collapsed() {
+    Python::with_gil(|py| {
+        let schema = py
+            .eval(c"{'type': 'union', 'return_label': True, 'choices': [({'type': 'int'}, 'number')]}", None, None)
+            .unwrap();
+        let validator = build_validator(schema.downcast().unwrap(), None, &mut DefinitionsBuilder::new()).unwrap();
+
+        let mut state = ValidationState::new(false);
+        let input = py.eval(c"1", None, None).unwrap();
+        let result = validator.validate(py, &input, &mut state).unwrap();
+        let (label, value): (String, i64) = result.extract(py).unwrap();
+        assert_eq!(label, "number");
+        assert_eq!(value, 1);
+    });
+}
+11. Coercion Allowlists Need a Scalar Choice
+rust
+#[test]
+fn test_allowed_coercions_rejected_without_scalar_choices() {
+    Python::with_gil(|py| {
+        let schema = py
+            .eval(
+                c"{'type': 'union', 'allowed_coercions': ['str->int'], 'choices': [
+                    {'type': 'list', 'items_schema': {'type': 'int'}},
+                    {'type': 'dict', 'keys_schema': {'type': 'str'}, 'values_schema': {'type': 'int'}},
+                ]}",
+                None,
+                None,
+            )
+            .unwrap();
+        let result = build_validator(schema.downcast().unwrap(), None, &mut DefinitionsBuilder::new());
+        assert!(result.is_err());
+    });
+}
+
+#[test]
+fn test_allowed_coercions_deny_scalar_choice() {
+    Python::with_gil(|py| {
+        let schema = py
+            .eval(
+                c"{'type': 'union', 'allowed_coercions': ['str->int'], 'choices': [{'type': 'bool'}, {'type': 'int'}]}",
+                None,
+                None,
+            )
+            .unwrap();
+        let validator = build_validator(schema.downcast().unwrap(), None, &mut DefinitionsBuilder::new()).unwrap();
+
+        // str->bool isn't allowed, so "1" becomes an int rather than `True`
+        let mut state = ValidationState::new(false);
+        let input = py.eval(c"'1'", None, None).unwrap();
+        let result = validator.validate(py, &input, &mut state).unwrap();
+        assert!(result.bind(py).is_exact_instance_of::<PyInt>());
+    });
+}
+12. Composite Discriminators Need Tuple Tags
+rust
+#[test]
+fn test_composite_discriminator_dispatches_on_tuple_tags() {
+    Python::with_gil(|py| {
+        let schema = py
+            .eval(
+                c"{'type': 'tagged-union', 'discriminator': ('kind', 'version'), 'choices': {
+                    ('cat', 1): {'type': 'typed-dict', 'fields': {
+                        'meow': {'type': 'typed-dict-field', 'schema': {'type': 'int'}},
+                    }},
+                    ('cat', 2): {'type': 'typed-dict', 'fields': {
+                        'meow': {'type': 'typed-dict-field', 'schema': {'type': 'str'}},
+                    }},
+                }}",
+                None,
+                None,
+            )
+            .unwrap();
+        let validator = build_validator(schema.downcast().unwrap(), None, &mut DefinitionsBuilder::new()).unwrap();
+
+        let mut state = ValidationState::new(false);
+        let input = py.eval(c"{'kind': 'cat', 'version': 2, 'meow': 'loud'}", None, None).unwrap();
+        assert!(validator.validate(py, &input, &mut state).is_ok());
+    });
+}
+
+#[test]
+fn test_composite_discriminator_rejects_non_tuple_tags() {
+    Python::with_gil(|py| {
+        for choices in [c"{'cat': {'type': 'int'}}", c"{('cat', 1, 'extra'): {'type': 'int'}}"] {
+            let choices = py.eval(choices, None, None).unwrap();
+            let schema = PyDict::new(py);
+            schema.set_item("type", "tagged-union").unwrap();
+            schema.set_item("discriminator", ("kind", "version")).unwrap();
+            schema.set_item("choices", choices).unwrap();
+            assert!(build_validator(&schema, None, &mut DefinitionsBuilder::new()).is_err());
+        }
+    });
+}
+13. Tie Breaks, Ambiguity, Selectors and Closest Errors
+rust
+#[test]
+fn test_tie_break_policies() {
+    Python::with_gil(|py| {
+        let selected_label = |schema: &std::ffi::CStr| {
+            let schema = py.eval(schema, None, None).unwrap();
+            let validator = build_validator(schema.downcast().unwrap(), None, &mut DefinitionsBuilder::new()).unwrap();
+            let mut state = ValidationState::new(false);
+            let input = py.eval(c"1", None, None).unwrap();
+            let result = validator.validate(py, &input, &mut state).unwrap();
+            result.extract::<(String, i64)>(py).unwrap().0
+        };
+
+        // both choices match exactly, so the tie is broken by the policy
+        assert_eq!(
+            selected_label(c"{'type': 'union', 'return_label': True, 'choices': [
+                ({'type': 'int'}, 'a'), ({'type': 'int'}, 'b'),
+            ]}"),
+            "a"
+        );
+        assert_eq!(
+            selected_label(c"{'type': 'union', 'return_label': True, 'tie_break': 'priority', 'choices': [
+                ({'type': 'int'}, 'a'), ({'type': 'int'}, 'b', {'priority': 1}),
+            ]}"),
+            "b"
+        );
+        assert_eq!(
+            selected_label(c"{'type': 'union', 'return_label': True, 'tie_break': 'first_declared', 'choices': [
+                ({'type': 'int'}, 'a'), ({'type': 'int'}, 'b', {'priority': 1}),
+            ]}"),
+            "a"
+        );
+        assert_eq!(
+            selected_label(c"{'type': 'union', 'return_label': True, 'tie_break': 'last_declared', 'choices': [
+                ({'type': 'int'}, 'a'), ({'type': 'int'}, 'b'),
+            ]}"),
+            "b"
+        );
+    });
+}
+
+#[test]
+fn test_tie_break_raise_and_exclusive_mode() {
+    Python::with_gil(|py| {
+        for schema in [
+            c"{'type': 'union', 'tie_break': 'raise', 'choices': [({'type': 'int'}, 'a'), ({'type': 'int'}, 'b')]}",
+            c"{'type': 'union', 'mode': 'exclusive', 'choices': [({'type': 'int'}, 'a'), ({'type': 'int'}, 'b')]}",
+        ] {
+            let schema = py.eval(schema, None, None).unwrap();
+            let validator = build_validator(schema.downcast().unwrap(), None, &mut DefinitionsBuilder::new()).unwrap();
+            let mut state = ValidationState::new(false);
+
+            let input = py.eval(c"1", None, None).unwrap();
+            let Err(ValError::LineErrors(lines)) = validator.validate(py, &input, &mut state) else {
+                panic!("expected an ambiguity error");
+            };
+            assert!(matches!(
+                &lines[0].error_type,
+                ErrorType::UnionAmbiguous { matched_choices, .. } if matched_choices == "a, b"
+            ));
+        }
+
+        // only one choice matches, so there's nothing ambiguous
+        let schema = py
+            .eval(c"{'type': 'union', 'mode': 'exclusive', 'choices': [{'type': 'int'}, {'type': 'str'}]}", None, None)
+            .unwrap();
+        let validator = build_validator(schema.downcast().unwrap(), None, &mut DefinitionsBuilder::new()).unwrap();
+        let mut state = ValidationState::new(false);
+        let input = py.eval(c"1", None, None).unwrap();
+        assert!(validator.validate(py, &input, &mut state).is_ok());
+
+        // exclusive mode always raises, so another tie break is a schema error
+        let schema = py
+            .eval(
+                c"{'type': 'union', 'mode': 'exclusive', 'tie_break': 'first_declared', 'choices': [
+                    {'type': 'int'}, {'type': 'str'},
+                ]}",
+                None,
+                None,
+            )
+            .unwrap();
+        assert!(build_validator(schema.downcast().unwrap(), None, &mut DefinitionsBuilder::new()).is_err());
+    });
+}
+
+#[test]
+fn test_selector_picks_choice() {
+    Python::with_gil(|py| {
+        let schema = py
+            .eval(
+                c"{'type': 'union', 'return_label': True, 'choices': [
+                    ({'type': 'str'}, 'text'),
+                    ({'type': 'int'}, 'number'),
+                ], 'selector': lambda v: (
+                    {'by_label': 'number', 'by_index': 1, 'unknown': 'other'}.get(v.get('route'))
+                    if isinstance(v, dict) else None
+                )}",
+                None,
+                None,
+            )
+            .unwrap();
+        let validator = build_validator(schema.downcast().unwrap(), None, &mut DefinitionsBuilder::new()).unwrap();
+        let mut state = ValidationState::new(false);
+
+        // by label and by index the dict only reaches the int choice, which rejects it
+        for route in [c"{'route': 'by_label'}", c"{'route': 'by_index'}"] {
+            let input = py.eval(route, None, None).unwrap();
+            let Err(ValError::LineErrors(lines)) = validator.validate(py, &input, &mut state) else {
+                panic!("expected the selected choice to fail");
+            };
+            assert!(lines.iter().all(|line| line.location.to_string().starts_with("number")));
+        }
+
+        // a choice the union doesn't have
+        let input = py.eval(c"{'route': 'unknown'}", None, None).unwrap();
+        let Err(ValError::LineErrors(lines)) = validator.validate(py, &input, &mut state) else {
+            panic!("expected an unknown choice error");
+        };
+        assert!(matches!(&lines[0].error_type, ErrorType::UnionTagInvalid { tag, .. } if tag == "other"));
+
+        // `None` falls back to smart mode
+        let input = py.eval(c"'abc'", None, None).unwrap();
+        let result = validator.validate(py, &input, &mut state).unwrap();
+        assert_eq!(result.extract::<(String, String)>(py).unwrap().0, "text");
+    });
+}
+
+#[test]
+fn test_closest_error_mode() {
+    Python::with_gil(|py| {
+        let choices = "[
+            ({'type': 'typed-dict', 'fields': {
+                'a': {'type': 'typed-dict-field', 'schema': {'type': 'int'}},
+                'b': {'type': 'typed-dict-field', 'schema': {'type': 'int'}},
+            }}, 'AB'),
+            ({'type': 'typed-dict', 'fields': {
+                'x': {'type': 'typed-dict-field', 'schema': {'type': 'int'}},
+                'y': {'type': 'typed-dict-field', 'schema': {'type': 'int'}},
+            }}, 'XY'),
+        ]";
+        let errors = |error_mode: &str| {
+            let schema = std::ffi::CString::new(format!(
+                "{{'type': 'union', 'error_mode': '{error_mode}', 'choices': {choices}}}"
+            ))
+            .unwrap();
+            let schema = py.eval(&schema, None, None).unwrap();
+            let validator = build_validator(schema.downcast().unwrap(), None, &mut DefinitionsBuilder::new()).unwrap();
+            let mut state = ValidationState::new(false);
+            let input = py.eval(c"{'a': 1, 'b': 'x'}", None, None).unwrap();
+            let Err(ValError::LineErrors(lines)) = validator.validate(py, &input, &mut state) else {
+                panic!("expected line errors");
+            };
+            lines.iter().map(|line| line.location.to_string()).collect::<Vec<_>>()
+        };
+
+        // `AB` fails on one field, `XY` is missing both of its fields
+        assert_eq!(errors("closest"), ["AB.b"]);
+        assert_eq!(errors("all"), ["AB.b", "XY.x", "XY.y"]);
+    });
+}
+14. Tagged Union Tag Matching
+rust
+#[test]
+fn test_tag_alias_and_normalized_matching() {
+    Python::with_gil(|py| {
+        let schema = py
+            .eval(
+                c"{'type': 'tagged-union', 'discriminator': 'kind', 'tag_matching': 'normalized',
+                  'tag_aliases': {'credit_card': ['cc']}, 'choices': {
+                    'credit_card': {'type': 'typed-dict', 'fields': {
+                        'number': {'type': 'typed-dict-field', 'schema': {'type': 'int'}},
+                    }},
+                    'cash': {'type': 'typed-dict', 'fields': {
+                        'amount': {'type': 'typed-dict-field', 'schema': {'type': 'int'}},
+                    }},
+                }}",
+                None,
+                None,
+            )
+            .unwrap();
+        let validator = build_validator(schema.downcast().unwrap(), None, &mut DefinitionsBuilder::new()).unwrap();
+        let mut state = ValidationState::new(false);
+
+        // the alias and every spelling of the tag reach the credit card choice
+        for input in [
+            c"{'kind': 'cc', 'number': 1}",
+            c"{'kind': 'CreditCard', 'number': 1}",
+            c"{'kind': 'credit-card', 'number': 1}",
+        ] {
+            let input = py.eval(input, None, None).unwrap();
+            assert!(validator.validate(py, &input, &mut state).is_ok());
+        }
+
+        // shown by the credit card choice's error for a cash payload
+        let input = py.eval(c"{'kind': 'cc', 'amount': 1}", None, None).unwrap();
+        let Err(ValError::LineErrors(lines)) = validator.validate(py, &input, &mut state) else {
+            panic!("expected line errors");
+        };
+        assert_eq!(lines[0].location.to_string(), "credit_card.number");
+    });
+}
+
+#[test]
+fn test_lax_tag_coercion() {
+    Python::with_gil(|py| {
+        let choices = "{
+            1: {'type': 'typed-dict', 'fields': {'a': {'type': 'typed-dict-field', 'schema': {'type': 'int'}}}},
+            2: {'type': 'typed-dict', 'fields': {'b': {'type': 'typed-dict-field', 'schema': {'type': 'int'}}}},
+        }";
+        let validate = |strict: bool| {
+            let strict = if strict { "True" } else { "False" };
+            let schema = std::ffi::CString::new(format!(
+                "{{'type': 'tagged-union', 'discriminator': 'kind', 'strict': {strict}, 'choices': {choices}}}"
+            ))
+            .unwrap();
+            let schema = py.eval(&schema, None, None).unwrap();
+            let validator = build_validator(schema.downcast().unwrap(), None, &mut DefinitionsBuilder::new()).unwrap();
+            let mut state = ValidationState::new(false);
+            let input = py.eval(c"{'kind': '1', 'a': 1}", None, None).unwrap();
+            validator.validate(py, &input, &mut state)
+        };
+
+        // "1" reaches the `1` choice in lax mode only
+        assert!(validate(false).is_ok());
+        let Err(ValError::LineErrors(lines)) = validate(true) else {
+            panic!("expected an invalid tag error");
+        };
+        assert!(matches!(lines[0].error_type, ErrorType::UnionTagInvalid { .. }));
+    });
+}
+
+#[test]
+fn test_tag_predicates() {
+    Python::with_gil(|py| {
+        let schema = py
+            .eval(
+                c"{'type': 'tagged-union', 'discriminator': 'version', 'tag_aliases': {1: ['7']},
+                  'tag_predicates': [{'tag': 1, 'ge': 1, 'le': 3}, {'tag': 4, 'ge': 4}], 'choices': {
+                    1: {'type': 'typed-dict', 'fields': {'a': {'type': 'typed-dict-field', 'schema': {'type': 'int'}}}},
+                    4: {'type': 'typed-dict', 'fields': {'b': {'type': 'typed-dict-field', 'schema': {'type': 'int'}}}},
+                }}",
+                None,
+                None,
+            )
+            .unwrap();
+        let validator = build_validator(schema.downcast().unwrap(), None, &mut DefinitionsBuilder::new()).unwrap();
+        let mut state = ValidationState::new(false);
+
+        // 2 is in the first range, 9 in the second, and the alias `'7'` is matched before the ranges are
+        for input in [c"{'version': 2, 'a': 1}", c"{'version': 9, 'b': 1}", c"{'version': '7', 'a': 1}"] {
+            let input = py.eval(input, None, None).unwrap();
+            assert!(validator.validate(py, &input, &mut state).is_ok());
+        }
+        let input = py.eval(c"{'version': 9, 'a': 1}", None, None).unwrap();
+        let Err(ValError::LineErrors(lines)) = validator.validate(py, &input, &mut state) else {
+            panic!("expected line errors");
+        };
+        assert_eq!(lines[0].location.to_string(), "4.b");
+
+        let schema = py
+            .eval(
+                c"{'type': 'tagged-union', 'discriminator': 'kind', 'tag_predicates': [
+                    {'tag': 'created', 'prefix': 'created.'},
+                    {'tag': 'deleted', 'pattern': '^deleted[0-9]+$'},
+                  ], 'choices': {
+                    'created': {'type': 'typed-dict', 'fields': {
+                        'a': {'type': 'typed-dict-field', 'schema': {'type': 'int'}},
+                    }},
+                    'deleted': {'type': 'typed-dict', 'fields': {
+                        'b': {'type': 'typed-dict-field', 'schema': {'type': 'int'}},
+                    }},
+                }}",
+                None,
+                None,
+            )
+            .unwrap();
+        let validator = build_validator(schema.downcast().unwrap(), None, &mut DefinitionsBuilder::new()).unwrap();
+        for input in [c"{'kind': 'created.user', 'a': 1}", c"{'kind': 'deleted42', 'b': 1}"] {
+            let input = py.eval(input, None, None).unwrap();
+            assert!(validator.validate(py, &input, &mut state).is_ok());
+        }
+        let input = py.eval(c"{'kind': 'deleted-user', 'b': 1}", None, None).unwrap();
+        assert!(validator.validate(py, &input, &mut state).is_err());
+    });
+}
+
+#[test]
+fn test_tag_matching_build_errors() {
+    Python::with_gil(|py| {
+        for schema in [
+            // the same alias for two tags
+            c"{'type': 'tagged-union', 'discriminator': 'kind', 'tag_aliases': {'a': ['x'], 'b': ['x']},
+              'choices': {'a': {'type': 'dict'}, 'b': {'type': 'dict'}}}",
+            // two tags normalizing to the same string
+            c"{'type': 'tagged-union', 'discriminator': 'kind', 'tag_matching': 'normalized',
+              'choices': {'credit_card': {'type': 'dict'}, 'CreditCard': {'type': 'dict'}}}",
+            // a range no tag can fall in
+            c"{'type': 'tagged-union', 'discriminator': 'kind', 'tag_predicates': [{'tag': 1, 'ge': 5, 'le': 2}],
+              'choices': {1: {'type': 'dict'}}}",
+        ] {
+            let schema = py.eval(schema, None, None).unwrap();
+            assert!(build_validator(schema.downcast().unwrap(), None, &mut DefinitionsBuilder::new()).is_err());
+        }
+    });
+}
+15. Intersections, Negation and Tag Fallbacks
+rust
+#[test]
+fn test_intersection_merges() {
+    Python::with_gil(|py| {
+        let choices = "[
+            {'type': 'typed-dict', 'fields': {'a': {'type': 'typed-dict-field', 'schema': {'type': 'int'}}}},
+            {'type': 'typed-dict', 'fields': {'b': {'type': 'typed-dict-field', 'schema': {'type': 'int'}}}},
+        ]";
+        let validate = |merge: &str, input: &std::ffi::CStr| {
+            let schema = std::ffi::CString::new(format!(
+                "{{'type': 'intersection', 'merge': '{merge}', 'choices': {choices}}}"
+            ))
+            .unwrap();
+            let schema = py.eval(&schema, None, None).unwrap();
+            let validator = build_validator(schema.downcast().unwrap(), None, &mut DefinitionsBuilder::new()).unwrap();
+            let mut state = ValidationState::new(false);
+            let input = py.eval(input, None, None).unwrap();
+            validator.validate(py, &input, &mut state)
+        };
+
+        let result = validate("last", c"{'a': '1', 'b': 2}").unwrap();
+        assert!(result.bind(py).eq(py.eval(c"{'b': 2}", None, None).unwrap()).unwrap());
+        let result = validate("merge_dicts", c"{'a': '1', 'b': 2}").unwrap();
+        assert!(result.bind(py).eq(py.eval(c"{'a': 1, 'b': 2}", None, None).unwrap()).unwrap());
+
+        // every choice has to pass, and all their errors are reported
+        let Err(ValError::LineErrors(lines)) = validate("last", c"{}") else {
+            panic!("expected line errors");
+        };
+        assert_eq!(lines.len(), 2);
+    });
+}
+
+#[test]
+fn test_intersection_identical_and_non_dict_merge() {
+    Python::with_gil(|py| {
+        let schema = py
+            .eval(
+                c"{'type': 'intersection', 'merge': 'identical', 'choices': [
+                    {'type': 'str'}, {'type': 'str', 'to_upper': True},
+                ]}",
+                None,
+                None,
+            )
+            .unwrap();
+        let validator = build_validator(schema.downcast().unwrap(), None, &mut DefinitionsBuilder::new()).unwrap();
+        let mut state = ValidationState::new(false);
+
+        let input = py.eval(c"'ABC'", None, None).unwrap();
+        assert!(validator.validate(py, &input, &mut state).is_ok());
+        let input = py.eval(c"'abc'", None, None).unwrap();
+        let Err(ValError::LineErrors(lines)) = validator.validate(py, &input, &mut state) else {
+            panic!("expected a conflict error");
+        };
+        assert!(matches!(lines[0].error_type, ErrorType::IntersectionConflict { .. }));
+
+        // a choice returning something other than a dict can't be merged
+        let schema = py
+            .eval(
+                c"{'type': 'intersection', 'merge': 'merge_dicts', 'choices': [
+                    {'type': 'dict'},
+                    ({'type': 'function-plain', 'function': {'type': 'no-info', 'function': lambda v: 1}}, 'one'),
+                ]}",
+                None,
+                None,
+            )
+            .unwrap();
+        let validator = build_validator(schema.downcast().unwrap(), None, &mut DefinitionsBuilder::new()).unwrap();
+        let input = py.eval(c"{'a': 1}", None, None).unwrap();
+        let Err(ValError::LineErrors(lines)) = validator.validate(py, &input, &mut state) else {
+            panic!("expected a dict type error");
+        };
+        assert!(matches!(lines[0].error_type, ErrorType::DictType { .. }));
+        assert_eq!(lines[0].location.to_string(), "one");
+    });
+}
+
+#[test]
+fn test_not_excludes_inner_matches() {
+    Python::with_gil(|py| {
+        // any string except the reserved names
+        let schema = py
+            .eval(
+                c"{'type': 'intersection', 'choices': [
+                    {'type': 'str'},
+                    {'type': 'not', 'schema': {'type': 'literal', 'expected': ['admin', 'root']}},
+                ]}",
+                None,
+                None,
+            )
+            .unwrap();
+        let validator = build_validator(schema.downcast().unwrap(), None, &mut DefinitionsBuilder::new()).unwrap();
+        let mut state = ValidationState::new(false);
+
+        let input = py.eval(c"'bob'", None, None).unwrap();
+        let result = validator.validate(py, &input, &mut state).unwrap();
+        assert_eq!(result.extract::<String>(py).unwrap(), "bob");
+
+        let input = py.eval(c"'admin'", None, None).unwrap();
+        let Err(ValError::LineErrors(lines)) = validator.validate(py, &input, &mut state) else {
+            panic!("expected an excluded error");
+        };
+        assert!(matches!(lines[0].error_type, ErrorType::Excluded { .. }));
+    });
+}
+
+#[test]
+fn test_tag_fallbacks() {
+    Python::with_gil(|py| {
+        let choices = "{
+            'cat': {'type': 'typed-dict', 'fields': {'meow': {'type': 'typed-dict-field', 'schema': {'type': 'int'}}}},
+            'dog': {'type': 'typed-dict', 'fields': {'bark': {'type': 'typed-dict-field', 'schema': {'type': 'int'}}}},
+        }";
+        let build = |fallback: &str| {
+            let schema = std::ffi::CString::new(format!(
+                "{{'type': 'tagged-union', 'discriminator': 'kind', 'fallback': {fallback}, 'choices': {choices}}}"
+            ))
+            .unwrap();
+            let schema = py.eval(&schema, None, None).unwrap();
+            build_validator(schema.downcast().unwrap(), None, &mut DefinitionsBuilder::new()).unwrap()
+        };
+        let mut state = ValidationState::new(false);
+
+        // a fallback schema takes unknown and missing tags
+        let validator = build("{'type': 'dict'}");
+        for input in [c"{'kind': 'bird', 'tweet': 1}", c"{'tweet': 1}"] {
+            let input = py.eval(input, None, None).unwrap();
+            assert!(validator.validate(py, &input, &mut state).is_ok());
+        }
+
+        // the smart fallback tries every choice
+        let validator = build("'smart'");
+        for input in [c"{'kind': 'bird', 'meow': 1}", c"{'bark': 1}"] {
+            let input = py.eval(input, None, None).unwrap();
+            assert!(validator.validate(py, &input, &mut state).is_ok());
+        }
+        let input = py.eval(c"{'tweet': 1}", None, None).unwrap();
+        let Err(ValError::LineErrors(lines)) = validator.validate(py, &input, &mut state) else {
+            panic!("expected line errors");
+        };
+        assert_eq!(lines.len(), 2);
+    });
+}
@@ -0,0 +1,148 @@
+use std::sync::Arc;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::errors::ValResult;
+use crate::input::Input;
+use crate::tools::SchemaDict;
+
+use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+
+/// Forces strict or lax validation for the wrapped schema, whatever the strictness of its parent.
+#[derive(Debug)]
+pub struct StrictOverrideValidator {
+    validator: Box<CombinedValidator>,
+    strict: bool,
+    name: String,
+}
+
+impl BuildValidator for StrictOverrideValidator {
+    const EXPECTED_TYPE: &'static str = "strict-override";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let sub_schema = schema.get_as_req(intern!(py, "schema"))?;
+        let validator = Box::new(build_validator(&sub_schema, config, definitions)?);
+        let name = format!("{}[{}]", Self::EXPECTED_TYPE, validator.get_name());
+        Ok(Self {
+            validator,
+            strict: schema.get_as_req(intern!(py, "strict"))?,
+            name,
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(StrictOverrideValidator { validator });
+
+impl Validator for StrictOverrideValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        with_strict_override(state, Some(self.strict), |state| self.validator.validate(py, input, state))
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Run `f` with `state.strict` set to `strict` if given, restoring the previous strictness
+/// afterwards whatever the outcome
+pub fn with_strict_override<'s, 'py, T>(
+    state: &mut ValidationState<'s, 'py>,
+    strict: Option<bool>,
+    f: impl FnOnce(&mut ValidationState<'s, 'py>) -> T,
+) -> T {
+    let Some(strict) = strict else {
+        return f(state);
+    };
+    let old_strict = state.strict;
+    state.strict = strict;
+    let result = f(state);
+    state.strict = old_strict;
+    result
+}
+
+/// Strictness per kind of validator, e.g. strict ints and bools but lax dates and decimals, from a
+/// `strict_profile` mapping schema types to `True` for strict or `False` for lax.
+#[derive(Debug)]
+pub struct StrictProfile(Vec<(String, bool)>);
+
+impl StrictProfile {
+    pub fn build(profile: &Bound<'_, PyDict>) -> PyResult<Self> {
+        profile
+            .iter()
+            .map(|(schema_type, strict)| Ok((schema_type.extract()?, strict.extract()?)))
+            .collect::<PyResult<_>>()
+            .map(Self)
+    }
+
+    pub fn get(&self, schema_type: &str) -> Option<bool> {
+        self.0.iter().find(|(t, _)| t == schema_type).map(|(_, strict)| *strict)
+    }
+}
+
+/// Run `f` with `profile` as `state.strict_profile` if given, restoring the previous profile
+/// afterwards whatever the outcome
+pub fn with_strict_profile<'s, 'py, T>(
+    state: &mut ValidationState<'s, 'py>,
+    profile: Option<&Arc<StrictProfile>>,
+    f: impl FnOnce(&mut ValidationState<'s, 'py>) -> T,
+) -> T {
+    let Some(profile) = profile else {
+        return f(state);
+    };
+    let old_profile = state.strict_profile.replace(profile.clone());
+    let result = f(state);
+    state.strict_profile = old_profile;
+    result
+}
+
+impl ValidationState<'_, '_> {
+    /// Strictness for a validator of `schema_type`, used by validators wherever they'd read
+    /// `strict`: the current profile's entry for the type if it has one, otherwise `strict`.
+    pub fn strict_for(&self, schema_type: &str) -> bool {
+        self.strict_profile
+            .as_ref()
+            .and_then(|profile| profile.get(schema_type))
+            .unwrap_or(self.strict)
+    }
+}
+
+/// Longer chains of wrappers than this are taken to be a recursive definition and give up
+const MAX_WRAPPER_DEPTH: usize = 32;
+
+impl CombinedValidator {
+    /// Find a strictness override on this validator, either a `strict-override` schema or a field
+    /// with `strict` set, looking through validators that only wrap another one: nullable, default,
+    /// function-after and definition refs.
+    pub fn strict_override(&self) -> Option<bool> {
+        let mut validator = self;
+        for _ in 0..MAX_WRAPPER_DEPTH {
+            validator = match validator {
+                Self::StrictOverride(v) => return Some(v.strict),
+                Self::FieldValidator(fv) => match fv.strict {
+                    Some(strict) => return Some(strict),
+                    None => fv.validator.as_ref(),
+                },
+                Self::Nullable(v) => v.validator.as_ref(),
+                Self::WithDefault(v) => v.validator.as_ref(),
+                Self::FunctionAfter(v) => v.validator.as_ref(),
+                // not filled in yet while definitions are still being built
+                Self::DefinitionRef(v) => v.definition.get()?,
+                _ => return None,
+            };
+        }
+        None
+    }
+}
@@ -1,21 +1,28 @@
+use std::cmp::{Ordering, Reverse};
+use std::ffi::CString;
 use std::fmt::Write;
 use std::str::FromStr;
+use std::sync::Arc;
 
 use crate::py_gc::PyGcTraverse;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList, PyString, PyTuple};
+use pyo3::exceptions::PyDeprecationWarning;
+use pyo3::types::{PyBool, PyBytes, PyDict, PyFloat, PyFrozenSet, PyInt, PyList, PySet, PyString, PyTuple};
 use pyo3::{intern, PyTraverseError, PyVisit};
+use regex::Regex;
 use smallvec::SmallVec;
 
 use crate::build_tools::py_schema_err;
 use crate::build_tools::schema_or_config;
 use crate::common::union::{Discriminator, SMALL_UNION_THRESHOLD};
-use crate::errors::{ErrorType, ToErrorValue, ValError, ValLineError, ValResult};
+use crate::errors::{ErrorType, Location, ToErrorValue, ValError, ValLineError, ValResult};
 use crate::input::{BorrowInput, Input, ValidatedDict};
+use crate::lookup_key::LookupKey;
 use crate::tools::SchemaDict;
 
 use super::custom_error::CustomError;
 use super::literal::LiteralLookup;
+use super::strict_override::{with_strict_profile, StrictProfile};
 use super::{
     build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator,
 };
@@ -24,6 +31,10 @@ use super::{
 enum UnionMode {
     Smart,
     LeftToRight,
+    /// smart mode which fails if more than one choice ties for the best match, like JSON Schema
+    /// `oneOf`, the same as smart mode with `tie_break='raise'`, so it can't be given another `tie_break`
+    Exclusive,
+    StrictThenLax,
 }
 
 impl FromStr for UnionMode {
@@ -33,7 +44,73 @@ impl FromStr for UnionMode {
         match s {
             "smart" => Ok(Self::Smart),
             "left_to_right" => Ok(Self::LeftToRight),
-            s => py_schema_err!("Invalid union mode: `{}`, expected `smart` or `left_to_right`", s),
+            "exclusive" => Ok(Self::Exclusive),
+            "strict_then_lax" => Ok(Self::StrictThenLax),
+            s => py_schema_err!(
+                "Invalid union mode: `{}`, expected `smart`, `left_to_right`, `exclusive` or `strict_then_lax`",
+                s
+            ),
+        }
+    }
+}
+
+/// How smart mode picks between successful choices that rank equally.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TieBreak {
+    /// the choice with the highest priority, then the first declared
+    Priority,
+    FirstDeclared,
+    LastDeclared,
+    /// fail with a `union_ambiguous` error
+    Raise,
+}
+
+impl FromStr for TieBreak {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "priority" => Ok(Self::Priority),
+            "first_declared" => Ok(Self::FirstDeclared),
+            "last_declared" => Ok(Self::LastDeclared),
+            "raise" => Ok(Self::Raise),
+            s => py_schema_err!(
+                "Invalid union tie break: `{}`, expected `priority`, `first_declared`, `last_declared` or `raise`",
+                s
+            ),
+        }
+    }
+}
+
+impl TieBreak {
+    /// Whether `new` should replace `current` when they rank equally.
+    fn prefers(self, new: &UnionChoice, current: &UnionChoice) -> bool {
+        match self {
+            Self::Priority => {
+                (new.priority, Reverse(new.declared_index)) > (current.priority, Reverse(current.declared_index))
+            }
+            Self::FirstDeclared => new.declared_index < current.declared_index,
+            Self::LastDeclared => new.declared_index > current.declared_index,
+            Self::Raise => false,
+        }
+    }
+}
+
+/// Which failed choices are reported when no choice matches.
+#[derive(Debug, Clone, Copy)]
+enum UnionErrorMode {
+    All,
+    Closest,
+}
+
+impl FromStr for UnionErrorMode {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(Self::All),
+            "closest" => Ok(Self::Closest),
+            s => py_schema_err!("Invalid union error mode: `{}`, expected `all` or `closest`", s),
         }
     }
 }
@@ -41,10 +118,28 @@ impl FromStr for UnionMode {
 #[derive(Debug)]
 pub struct UnionValidator {
     mode: UnionMode,
-    choices: Vec<(CombinedValidator, Option<String>)>,
+    choices: Vec<UnionChoice>,
     custom_error: Option<CustomError>,
+    error_mode: UnionErrorMode,
+    tie_break: TieBreak,
     name: String,
-    strict: bool, // NEW: Track union-level strictness
+    strict: Option<bool>, // NEW: Track union-level strictness, `None` inherits from the parent
+    /// put on the state while validating choices, so validators within them use it
+    strict_profile: Option<Arc<StrictProfile>>,
+    /// called with a record of how each choice fared every time smart mode runs, so the union's result is the
+    /// same whether or not it's being explained
+    explain: Option<PyObject>,
+    /// `(input type, schema type)` pairs of lax coercions allowed while validating choices,
+    /// `None` allows every coercion.
+    ///
+    /// Only choices whose own schema is one of `COERCIBLE_TYPES` are limited, by validating them
+    /// strictly when the coercion isn't allowed. Coercions inside model, collection or other
+    /// compound choices aren't limited, so a union with none of those choices is rejected when
+    /// building. Conversions strict mode accepts anyway, such as `int->float`, are always allowed.
+    allowed_coercions: Option<Vec<(String, String)>>,
+    selector: Option<ChoiceSelector>,
+    /// return `(label, value)` rather than the bare value, so callers can tell which choice matched
+    return_label: bool,
 }
 
 impl BuildValidator for UnionValidator {
@@ -56,47 +151,123 @@ impl BuildValidator for UnionValidator {
         definitions: &mut DefinitionsBuilder<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
         let py = schema.py();
-        let choices: Vec<(CombinedValidator, Option<String>)> = schema
-            .get_as_req::<Bound<'_, PyList>>(intern!(py, "choices"))?
-            .iter()
-            .map(|choice| {
-                let mut label: Option<String> = None;
-                let choice = match choice.downcast::<PyTuple>() {
-                    Ok(py_tuple) => {
-                        let choice = py_tuple.get_item(0)?;
-                        label = Some(py_tuple.get_item(1)?.to_string());
-                        choice
-                    }
-                    Err(_) => choice,
-                };
-                Ok((build_validator(&choice, config, definitions)?, label))
+        let auto_collapse = schema_or_config(
+            schema,
+            config,
+            intern!(py, "auto_collapse"),
+            intern!(py, "union_auto_collapse"),
+        )?
+        .unwrap_or(true);
+        let mode: Option<Bound<'_, PyString>> =
+            schema_or_config(schema, config, intern!(py, "mode"), intern!(py, "union_mode"))?;
+        let mode = mode.map_or(Ok(UnionMode::Smart), |mode| mode.to_str().and_then(UnionMode::from_str))?;
+        let tie_break = match (&mode, schema.get_as::<Bound<'_, PyString>>(intern!(py, "tie_break"))?) {
+            (UnionMode::Exclusive, Some(_)) => {
+                return py_schema_err!("Union `tie_break` can't be set with mode 'exclusive', which always raises");
+            }
+            (UnionMode::Exclusive, None) => TieBreak::Raise,
+            (_, Some(tie_break)) => TieBreak::from_str(tie_break.to_str()?)?,
+            (_, None) => TieBreak::Priority,
+        };
+        let error_mode = schema
+            .get_as::<Bound<'_, PyString>>(intern!(py, "error_mode"))?
+            .map_or(Ok(UnionErrorMode::All), |mode| mode.to_str().and_then(UnionErrorMode::from_str))?;
+        
+        // NEW: Get strict mode from schema or config, left unset so it's inherited rather than forced lax
+        let key = intern!(py, "strict");
+        let strict = schema_or_config(schema, config, key, key)?;
+        // maps schema types to the strictness to use for them, e.g. strict ints but lax dates, for
+        // the choices and for everything validated within them
+        let key = intern!(py, "strict_profile");
+        let strict_profile: Option<Bound<'_, PyDict>> = schema_or_config(schema, config, key, key)?;
+        let strict_profile = strict_profile
+            .map(|profile| StrictProfile::build(&profile).map(Arc::new))
+            .transpose()?;
+        let explain = schema
+            .get_as::<Bound<'_, PyAny>>(intern!(py, "explain"))?
+            .map(|func| {
+                if func.is_callable() {
+                    Ok(func.unbind())
+                } else {
+                    py_schema_err!("Union `explain` must be callable")
+                }
             })
-            .collect::<PyResult<Vec<(CombinedValidator, Option<String>)>>>()?;
+            .transpose()?;
+        let return_label = schema.get_as(intern!(py, "return_label"))?.unwrap_or(false);
+        let allowed_coercions = schema
+            .get_as::<Vec<String>>(intern!(py, "allowed_coercions"))?
+            .map(|coercions| coercions.iter().map(|c| parse_coercion(c)).collect::<PyResult<Vec<_>>>())
+            .transpose()?;
+        let selector = schema
+            .get_as::<Bound<'_, PyAny>>(intern!(py, "selector"))?
+            .map(|func| ChoiceSelector::new(&func))
+            .transpose()?;
 
-        let auto_collapse = || schema.get_as_req(intern!(py, "auto_collapse")).unwrap_or(true);
-        let mode = schema
-            .get_as::<Bound<'_, PyString>>(intern!(py, "mode"))?
-            .map_or(Ok(UnionMode::Smart), |mode| mode.to_str().and_then(UnionMode::from_str))?;
-        
-        // NEW: Get strict mode from schema
-        let strict = schema.get_as_req(intern!(py, "strict")).unwrap_or(false);
+        let schema_choices: Bound<'_, PyList> = schema.get_as_req(intern!(py, "choices"))?;
+
+        // a tagged union only behaves like a plain smart union, so don't infer one when other
+        // union-level behaviour has been asked for
+        let infer_discriminator = schema.get_as(intern!(py, "infer_discriminator"))?.unwrap_or(true);
+        if infer_discriminator
+            && matches!(mode, UnionMode::Smart)
+            && matches!(error_mode, UnionErrorMode::All)
+            && strict.is_none()
+            && strict_profile.is_none()
+            && explain.is_none()
+            && allowed_coercions.is_none()
+            && tie_break == TieBreak::Priority
+            && selector.is_none()
+            && !return_label
+        {
+            if let Some(tagged_schema) = infer_tagged_union_schema(schema, &schema_choices, config)? {
+                return TaggedUnionValidator::build(&tagged_schema, config, definitions);
+            }
+        }
+
+        // union-level options only apply if the union is kept, even around a single choice
+        let auto_collapse = auto_collapse
+            && explain.is_none()
+            && selector.is_none()
+            && !return_label
+            && allowed_coercions.is_none()
+            && strict_profile.is_none();
+
+        let mut choices = schema_choices
+            .iter()
+            .enumerate()
+            .map(|(index, choice)| UnionChoice::build(index, &choice, strict_profile.as_deref(), config, definitions))
+            .collect::<PyResult<Vec<UnionChoice>>>()?;
+
+        if allowed_coercions.is_some() && choices.iter().all(|choice| choice.coercion_target.is_none()) {
+            return py_schema_err!(
+                "Union `allowed_coercions` has no effect, no choice is of type {}",
+                COERCIBLE_TYPES.join(", ")
+            );
+        }
 
         match choices.len() {
             0 => py_schema_err!("One or more union choices required"),
-            1 if auto_collapse() => Ok(choices.into_iter().next().unwrap().0),
+            // the choice's own options only apply within a union
+            1 if auto_collapse && !choices[0].has_options() => Ok(choices.into_iter().next().unwrap().validator),
             _ => {
-                let descr = choices
-                    .iter()
-                    .map(|(choice, label)| label.as_deref().unwrap_or(choice.get_name()))
-                    .collect::<Vec<_>>()
-                    .join(",");
+                let descr = choices.iter().map(UnionChoice::name).collect::<Vec<_>>().join(",");
+                let selector = selector.map(|selector| selector.with_choices(&choices));
+                // stable, so choices with the same priority keep their declared order
+                choices.sort_by_key(|choice| Reverse(choice.priority));
 
                 Ok(Self {
                     mode,
                     choices,
                     custom_error: CustomError::build(schema, config, definitions)?,
+                    error_mode,
+                    tie_break,
                     name: format!("{}[{descr}]", Self::EXPECTED_TYPE),
                     strict, // NEW: Set strict mode
+                    strict_profile,
+                    explain,
+                    allowed_coercions,
+                    selector,
+                    return_label,
                 }
                 .into())
             }
@@ -104,15 +275,380 @@ impl BuildValidator for UnionValidator {
     }
 }
 
-impl UnionValidator {
-    // NEW: Helper to get effective strictness considering field overrides
-    fn effective_strict(&self, state: &ValidationState, validator: &CombinedValidator) -> bool {
-        if let Some(field_validator) = validator.as_field_validator() {
-            if let Some(strict) = field_validator.strict {
-                return strict;
+/// A python callable which picks the union choice to use for an input, by label or by index.
+#[derive(Debug)]
+struct ChoiceSelector {
+    func: PyObject,
+    repr: String,
+    choices_repr: String,
+}
+
+impl ChoiceSelector {
+    fn new(func: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if !func.is_callable() {
+            return py_schema_err!("Union `selector` must be callable");
+        }
+        Ok(Self {
+            func: func.clone().unbind(),
+            repr: format!("{}()", func.getattr(intern!(func.py(), "__name__"))?),
+            choices_repr: String::new(),
+        })
+    }
+
+    fn with_choices(self, choices: &[UnionChoice]) -> Self {
+        let choices_repr = choices
+            .iter()
+            .map(|choice| format!("'{}'", choice.name()))
+            .collect::<Vec<_>>()
+            .join(", ");
+        Self { choices_repr, ..self }
+    }
+}
+
+/// A single member of a plain union, along with the options given in its choice tuple.
+#[derive(Debug)]
+struct UnionChoice {
+    validator: CombinedValidator,
+    label: Option<String>,
+    /// overrides the strictness of the union and any field-level override
+    strict: Option<bool>,
+    /// choices with a higher priority are tried first
+    priority: i64,
+    /// position in the schema's `choices`, since choices are reordered by priority
+    declared_index: usize,
+    /// replaces this choice's errors when it fails
+    custom_error: Option<CustomError>,
+    /// warning message emitted when this choice is the one used
+    deprecated: Option<String>,
+    /// strictness for this kind of schema from the union's or config's `strict_profile`
+    profile_strict: Option<bool>,
+    /// the schema type, if it's a scalar type that lax mode coerces other types to
+    coercion_target: Option<String>,
+    input_kinds: InputKinds,
+}
+
+impl UnionChoice {
+    /// Build from either a bare schema, a `(schema, label)` tuple or a `(schema, label, options)` tuple.
+    fn build(
+        declared_index: usize,
+        choice: &Bound<'_, PyAny>,
+        strict_profile: Option<&StrictProfile>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<Self> {
+        let py = choice.py();
+        let mut label: Option<String> = None;
+        let mut options: Option<Bound<'_, PyDict>> = None;
+        let choice = match choice.downcast::<PyTuple>() {
+            Ok(py_tuple) => {
+                label = Some(py_tuple.get_item(1)?.to_string());
+                if py_tuple.len() > 2 {
+                    options = Some(py_tuple.get_item(2)?.downcast_into()?);
+                }
+                py_tuple.get_item(0)?
+            }
+            Err(_) => choice.clone(),
+        };
+
+        let choice_schema: &Bound<'_, PyDict> = choice.downcast()?;
+        let input_kinds = InputKinds::accepted_by(choice_schema, config)?;
+        let profile_strict = match strict_profile {
+            Some(strict_profile) => strict_profile.get(profile_schema_type(choice_schema)?.to_str()?),
+            None => None,
+        };
+        let schema_type: Bound<'_, PyString> = choice_schema.get_as_req(intern!(py, "type"))?;
+        let schema_type = schema_type.to_str()?;
+        let coercion_target = COERCIBLE_TYPES.contains(&schema_type).then(|| schema_type.to_owned());
+        let validator = build_validator(&choice, config, definitions)?;
+        let Some(options) = options else {
+            return Ok(Self {
+                validator,
+                label,
+                strict: None,
+                priority: 0,
+                declared_index,
+                custom_error: None,
+                deprecated: None,
+                profile_strict,
+                coercion_target,
+                input_kinds,
+            });
+        };
+
+        let deprecated = match options.get_item(intern!(py, "deprecated"))? {
+            Some(message) if message.is_instance_of::<PyString>() => Some(message.to_string()),
+            Some(flag) if flag.is_truthy()? => Some(format!(
+                "Union choice `{}` is deprecated",
+                label.as_deref().unwrap_or(validator.get_name())
+            )),
+            _ => None,
+        };
+        Ok(Self {
+            label,
+            strict: options.get_as(intern!(py, "strict"))?,
+            priority: options.get_as(intern!(py, "priority"))?.unwrap_or(0),
+            declared_index,
+            custom_error: CustomError::build(&options, config, definitions)?,
+            deprecated,
+            profile_strict,
+            coercion_target,
+            input_kinds,
+            validator,
+        })
+    }
+
+    fn name(&self) -> &str {
+        self.label.as_deref().unwrap_or(self.validator.get_name())
+    }
+
+    /// Whether the choice tuple set any options which change how this choice is validated.
+    fn has_options(&self) -> bool {
+        self.strict.is_some() || self.custom_error.is_some() || self.deprecated.is_some()
+    }
+
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        match (self.validator.validate(py, input, state), &self.custom_error) {
+            (Err(ValError::LineErrors(_)), Some(custom_error)) => Err(custom_error.as_val_error(input)),
+            (result, _) => result,
+        }
+    }
+
+    /// Called once this choice's result is the one the union returns.
+    fn warn_if_deprecated(&self, py: Python<'_>) -> PyResult<()> {
+        match self.deprecated {
+            Some(ref message) => {
+                let message = CString::new(message.as_str())?;
+                PyErr::warn(py, &py.get_type::<PyDeprecationWarning>(), &message, 1)
             }
+            None => Ok(()),
+        }
+    }
+}
+
+/// The schema type a choice's `strict_profile` entry is found by, looking through `nullable` and
+/// `default` wrappers so `int | None` uses the entry for `int`.
+fn profile_schema_type<'py>(schema: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyString>> {
+    let py = schema.py();
+    let mut schema = schema.clone();
+    loop {
+        let schema_type: Bound<'_, PyString> = schema.get_as_req(intern!(py, "type"))?;
+        match schema_type.to_str()? {
+            "nullable" | "default" => schema = schema.get_as_req(intern!(py, "schema"))?,
+            _ => return Ok(schema_type),
+        }
+    }
+}
+
+/// Schema types whose lax mode coerces from other input types, and so can be limited with
+/// `allowed_coercions` when they're a union choice themselves
+const COERCIBLE_TYPES: &[&str] = &[
+    "int",
+    "float",
+    "bool",
+    "str",
+    "bytes",
+    "decimal",
+    "complex",
+    "date",
+    "time",
+    "datetime",
+    "timedelta",
+    "uuid",
+];
+
+/// Parse an `allowed_coercions` entry like `"str->int"`.
+fn parse_coercion(coercion: &str) -> PyResult<(String, String)> {
+    match coercion.split_once("->") {
+        Some((from, to)) if !from.trim().is_empty() && !to.trim().is_empty() => {
+            Ok((from.trim().to_owned(), to.trim().to_owned()))
         }
-        self.strict || state.strict
+        _ => py_schema_err!(
+            "Invalid union coercion: `{}`, expected `<input type>-><schema type>`",
+            coercion
+        ),
+    }
+}
+
+/// Name of the input's type as used in `allowed_coercions`, e.g. `str` or `decimal`.
+fn input_type_name<'py>(input: &(impl Input<'py> + ?Sized)) -> Option<String> {
+    if input.is_none() {
+        return Some("none".to_owned());
+    }
+    if let Some(obj) = input.as_python() {
+        return obj.get_type().name().ok().map(|name| name.to_string().to_lowercase());
+    }
+    // JSON scalars, bool and int come first since strict float validation accepts them too
+    let name = if input.validate_bool(true).is_ok() {
+        "bool"
+    } else if input.exact_int().is_ok() {
+        "int"
+    } else if input.validate_float(true).is_ok() {
+        "float"
+    } else if input.exact_str().is_ok() {
+        "str"
+    } else {
+        return None;
+    };
+    Some(name.to_owned())
+}
+
+/// When every choice is a model or typed-dict with a required literal field of the same name, and
+/// the literal values don't overlap between choices, build the equivalent tagged-union schema.
+fn infer_tagged_union_schema<'py>(
+    schema: &Bound<'py, PyDict>,
+    schema_choices: &Bound<'py, PyList>,
+    config: Option<&Bound<'py, PyDict>>,
+) -> PyResult<Option<Bound<'py, PyDict>>> {
+    let py = schema.py();
+    if schema_choices.len() < 2 {
+        return Ok(None);
+    }
+
+    let mut choice_schemas = Vec::with_capacity(schema_choices.len());
+    let mut choice_fields = Vec::with_capacity(schema_choices.len());
+    for choice in schema_choices {
+        // labels and per-choice options can't be expressed on a tagged union, which locates errors by tag
+        let Ok(choice_schema) = choice.downcast_into::<PyDict>() else {
+            return Ok(None);
+        };
+        let Some(fields) = schema_fields(&choice_schema, config)? else {
+            return Ok(None);
+        };
+        choice_schemas.push(choice_schema);
+        choice_fields.push(fields);
+    }
+
+    'candidates: for field_name in choice_fields[0].0.keys() {
+        let seen_tags = PySet::empty(py)?;
+        let tagged_choices = PyDict::new(py);
+        for (choice_schema, (fields, total)) in choice_schemas.iter().zip(&choice_fields) {
+            let Some(expected) = literal_field_values(fields, *total, &field_name)? else {
+                continue 'candidates;
+            };
+            for tag in expected {
+                // unhashable or overlapping literals can't be used as tags
+                match seen_tags.contains(&tag) {
+                    Ok(false) => seen_tags.add(&tag)?,
+                    _ => continue 'candidates,
+                }
+                tagged_choices.set_item(&tag, choice_schema)?;
+            }
+        }
+
+        let tagged_schema = PyDict::new(py);
+        tagged_schema.set_item(intern!(py, "type"), TaggedUnionValidator::EXPECTED_TYPE)?;
+        tagged_schema.set_item(intern!(py, "choices"), tagged_choices)?;
+        tagged_schema.set_item(intern!(py, "discriminator"), field_name)?;
+        for key in [
+            intern!(py, "custom_error_type"),
+            intern!(py, "custom_error_message"),
+            intern!(py, "custom_error_context"),
+        ] {
+            if let Some(value) = schema.get_item(key)? {
+                tagged_schema.set_item(key, value)?;
+            }
+        }
+        return Ok(Some(tagged_schema));
+    }
+    Ok(None)
+}
+
+/// The `fields` dict of a model or typed-dict schema, and whether its fields are required unless they
+/// say otherwise, `None` for any other kind of schema.
+fn schema_fields<'py>(
+    schema: &Bound<'py, PyDict>,
+    config: Option<&Bound<'py, PyDict>>,
+) -> PyResult<Option<(Bound<'py, PyDict>, bool)>> {
+    let py = schema.py();
+    let schema_type: Bound<'_, PyString> = schema.get_as_req(intern!(py, "type"))?;
+    let fields = match schema_type.to_str()? {
+        "model" => {
+            let inner: Bound<'_, PyDict> = schema.get_as_req(intern!(py, "schema"))?;
+            let inner_type: Bound<'_, PyString> = inner.get_as_req(intern!(py, "type"))?;
+            match inner_type.to_str()? {
+                "model-fields" => inner.get_as(intern!(py, "fields"))?.map(|fields| (fields, true)),
+                _ => None,
+            }
+        }
+        "typed-dict" => {
+            // same resolution as the typed-dict validator, so `total=False` makes fields optional
+            let total = schema_or_config(schema, config, intern!(py, "total"), intern!(py, "typed_dict_total"))?
+                .unwrap_or(true);
+            schema.get_as(intern!(py, "fields"))?.map(|fields| (fields, total))
+        }
+        _ => None,
+    };
+    Ok(fields)
+}
+
+/// The expected values of a field if it's a required, un-aliased literal field. `total` is whether
+/// fields are required when they don't set `required`.
+fn literal_field_values<'py>(
+    fields: &Bound<'py, PyDict>,
+    total: bool,
+    field_name: &Bound<'py, PyAny>,
+) -> PyResult<Option<Bound<'py, PyList>>> {
+    let py = fields.py();
+    let Some(field) = fields.get_item(field_name)? else {
+        return Ok(None);
+    };
+    let field = field.downcast_into::<PyDict>()?;
+    // aliases and optional fields change how (or whether) the tag is found, so don't infer from them
+    let required = field.get_as(intern!(py, "required"))?.unwrap_or(total);
+    if field.contains(intern!(py, "validation_alias"))? || !required {
+        return Ok(None);
+    }
+    let field_schema: Bound<'_, PyDict> = field.get_as_req(intern!(py, "schema"))?;
+    let field_type: Bound<'_, PyString> = field_schema.get_as_req(intern!(py, "type"))?;
+    match field_type.to_str()? {
+        "literal" => field_schema.get_as(intern!(py, "expected")),
+        _ => Ok(None),
+    }
+}
+
+impl UnionValidator {
+    // NEW: Helper to get effective strictness considering field overrides
+    // The most specific explicit setting wins: the choice's options, then a field-level override,
+    // then the strict profile entry for the choice's type, then the union's own `strict`, so an
+    // explicit `strict=False` can relax a strict parent.
+    // Only when none of them is set is the parent's strictness inherited.
+    fn effective_strict(&self, state: &ValidationState, choice: &UnionChoice) -> bool {
+        self.choice_strict(choice).or(self.strict).unwrap_or(state.strict)
+    }
+
+    /// Strictness set for the choice itself, rather than inherited from the union or its parent.
+    fn choice_strict(&self, choice: &UnionChoice) -> Option<bool> {
+        choice
+            .strict
+            .or_else(|| choice.validator.strict_override())
+            .or(choice.profile_strict)
+    }
+
+    /// Strictness of a choice during a smart-mode run: everything is strict in the strict pass of
+    /// `strict_then_lax` mode, otherwise each choice gets its usual strictness.
+    fn pass_strict(&self, state: &ValidationState, choice: &UnionChoice, strict_pass: bool) -> bool {
+        strict_pass || self.effective_strict(state, choice)
+    }
+
+    /// Whether validating `choice` would need a coercion that's not in `allowed_coercions`, in which
+    /// case the choice is validated strictly so the coercion fails rather than succeeding as lax.
+    fn coercion_denied(&self, choice: &UnionChoice, input_type: Option<&str>) -> bool {
+        let (Some(allowed_coercions), Some(target), Some(input_type)) =
+            (&self.allowed_coercions, &choice.coercion_target, input_type)
+        else {
+            return false;
+        };
+        input_type != target && !allowed_coercions.iter().any(|(from, to)| from == input_type && to == target)
+    }
+
+    /// The input's type name, only worked out when the union limits coercions.
+    fn coercion_input_type<'py>(&self, input: &(impl Input<'py> + ?Sized)) -> Option<String> {
+        self.allowed_coercions.as_ref().and_then(|_| input_type_name(input))
     }
 
     fn validate_smart<'py>(
@@ -120,70 +656,196 @@ impl UnionValidator {
         py: Python<'py>,
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
-    ) -> ValResult<PyObject> {
-        let old_exactness = state.exactness;
-        let old_fields_set_count = state.fields_set_count;
+        strict_pass: bool,
+        mut explanation: Option<&mut SmartExplanation>,
+    ) -> ValResult<(PyObject, usize)> {
         let old_strict = state.strict; // NEW: Save original strict mode
 
         let mut errors = MaybeErrors::new(self.custom_error.as_ref());
-        let mut best_match: Option<(Py<PyAny>, Exactness, Option<usize>)> = None;
+        let mut best_match = BestMatch::new(state);
+        let input_kind = self.input_filter(input);
+        let input_type = self.coercion_input_type(input);
 
-        for (choice, label) in &self.choices {
-            state.exactness = Some(Exactness::Exact);
-            state.fields_set_count = None;
-            state.strict = self.effective_strict(state, choice); // NEW: Apply field-level strictness
+        for (index, choice) in self.choices.iter().enumerate() {
+            if !choice.input_kinds.intersects(input_kind) {
+                continue;
+            }
+            best_match.start_choice(state);
+            let effective_strict = self.coercion_denied(choice, input_type.as_deref())
+                || self.pass_strict(state, choice, strict_pass);
+            state.strict = effective_strict; // NEW: Apply field-level strictness
             
             let result = choice.validate(py, input, state);
             
             state.strict = old_strict; // NEW: Restore strict mode
 
+            if let Some(explanation) = explanation.as_deref_mut() {
+                explanation.record(index, result.is_ok(), state, effective_strict);
+            }
+
             match result {
-                Ok(new_success) => match (state.exactness, state.fields_set_count) {
-                    (Some(Exactness::Exact), None) => {
-                        state.exactness = old_exactness;
-                        state.fields_set_count = old_fields_set_count;
-                        return Ok(new_success);
-                    }
-                    _ => {
-                        debug_assert_ne!(state.exactness, None);
-                        let new_exactness = state.exactness.unwrap_or(Exactness::Lax);
-                        let new_fields_set_count = state.fields_set_count;
-
-                        let new_success_is_best_match = best_match
-                            .as_ref()
-                            .map_or(true, |(_, cur_exactness, cur_fields_set_count)| {
-                                match (*cur_fields_set_count, new_fields_set_count) {
-                                    (Some(cur), Some(new)) if cur != new => cur < new,
-                                    _ => *cur_exactness < new_exactness,
-                                }
-                            });
-
-                        if new_success_is_best_match {
-                            best_match = Some((new_success, new_exactness, new_fields_set_count));
-                        }
-                    }
-                },
+                // choices are tried in priority order, so nothing later can beat an exact match
+                Ok(new_success) if BestMatch::is_exact(state) && self.tie_break == TieBreak::Priority => {
+                    best_match.restore(state);
+                    choice.warn_if_deprecated(py)?;
+                    return Ok((new_success, index));
+                }
+                Ok(new_success) => best_match.push(state, new_success, index, |cur_index| {
+                    self.tie_break.prefers(choice, &self.choices[cur_index])
+                }),
                 Err(ValError::LineErrors(lines)) => {
-                    if best_match.is_none() {
-                        errors.push(choice, label.as_deref(), lines);
+                    if !best_match.is_found() {
+                        errors.push(choice, lines);
                     }
                 }
-                otherwise => return otherwise,
+                Err(err) => {
+                    best_match.restore(state);
+                    return Err(err);
+                }
+            }
+        }
+
+        if let Some((best_match, index, tied)) = best_match.finish(state) {
+            if self.tie_break == TieBreak::Raise && tied.len() > 1 {
+                let tied = tied.iter().map(|&index| self.choices[index].name()).collect::<Vec<_>>();
+                return Err(self.ambiguous_error(input, &tied));
             }
+            self.choices[index].warn_if_deprecated(py)?;
+            return Ok((best_match, index));
+        }
+
+        Err(errors.into_val_error(input, self.error_mode))
+    }
+
+    /// Classify the input so choices which can't possibly accept it can be skipped. If no choice
+    /// could accept it, every choice is tried so the usual errors are still reported.
+    fn input_filter<'py>(&self, input: &(impl Input<'py> + ?Sized)) -> InputKinds {
+        let input_kind = InputKinds::of_input(input);
+        if self.choices.iter().any(|choice| choice.input_kinds.intersects(input_kind)) {
+            input_kind
+        } else {
+            InputKinds::ALL
+        }
+    }
+
+    /// Run smart mode with strict forced on for every choice, and only fall back to a lax smart pass
+    /// when no choice matches strictly, so coercion is a last resort. The lax pass keeps the union's
+    /// and parent's strictness, so a strict union or parent is never relaxed by this mode, and in
+    /// that case the lax pass is skipped unless a choice is explicitly lax.
+    fn validate_strict_then_lax<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<(PyObject, usize)> {
+        // if every choice would be strict anyway, the lax pass would just repeat the strict one
+        let lax_pass_differs = self.choices.iter().any(|choice| !self.effective_strict(state, choice));
+        match self.validate_smart(py, input, state, true, None) {
+            // errors from the strict pass are discarded, the lax pass gives more useful ones
+            Err(ValError::LineErrors(_)) if lax_pass_differs => self.validate_smart(py, input, state, false, None),
+            otherwise => otherwise,
+        }
+    }
+
+    /// Validate against just the choice returned by the `selector`, either its label or its position
+    /// in the schema's `choices`.
+    fn validate_selected<'py>(
+        &self,
+        py: Python<'py>,
+        selector: &ChoiceSelector,
+        selected: &Bound<'py, PyAny>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+        explanation: Option<&mut SmartExplanation>,
+    ) -> ValResult<(PyObject, usize)> {
+        let index = match selected.extract::<usize>() {
+            Ok(index) => self.choices.iter().position(|choice| choice.declared_index == index),
+            Err(_) => match selected.downcast::<PyString>() {
+                Ok(label) => {
+                    let label = label.to_str()?;
+                    self.choices.iter().position(|choice| choice.name() == label)
+                }
+                Err(_) => None,
+            },
+        };
+        let Some(index) = index else {
+            return Err(match self.custom_error {
+                Some(ref custom_error) => custom_error.as_val_error(input),
+                None => ValError::new(
+                    ErrorType::UnionTagInvalid {
+                        discriminator: selector.repr.clone(),
+                        tag: selected.to_string(),
+                        expected_tags: selector.choices_repr.clone(),
+                        context: None,
+                    },
+                    input,
+                ),
+            });
+        };
+
+        let choice = &self.choices[index];
+        // measured on its own like in smart mode, so the explanation doesn't show the parent's exactness
+        let best_match = BestMatch::new(state);
+        best_match.start_choice(state);
+        let old_strict = state.strict;
+        let effective_strict = self.coercion_denied(choice, self.coercion_input_type(input).as_deref())
+            || self.effective_strict(state, choice);
+        state.strict = effective_strict;
+        let result = choice.validate(py, input, state);
+        state.strict = old_strict;
+
+        if let Some(explanation) = explanation {
+            explanation.record(index, result.is_ok(), state, effective_strict);
+        }
+        let rank = result.is_ok().then(|| MatchRank::from_state(state));
+        best_match.restore(state);
+        if let Some(rank) = rank {
+            rank.apply(state);
         }
 
-        state.exactness = old_exactness;
-        state.fields_set_count = old_fields_set_count;
+        match result {
+            Ok(value) => {
+                choice.warn_if_deprecated(py)?;
+                Ok((value, index))
+            }
+            Err(err) => Err(err.with_outer_location(choice.name())),
+        }
+    }
 
-        if let Some((best_match, exactness, fields_set_count)) = best_match {
-            state.floor_exactness(exactness);
-            if let Some(count) = fields_set_count {
-                state.add_fields_set(count);
+    /// Validate with the selector or the union's mode, returning the value and the index of the
+    /// choice that produced it.
+    fn validate_choices<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+        explanation: Option<&mut SmartExplanation>,
+    ) -> ValResult<(PyObject, usize)> {
+        if let Some(selector) = &self.selector {
+            let selected = selector.func.call1(py, (input.to_object(py)?,))?;
+            // no choice picked, fall back to the union's mode
+            if !selected.is_none(py) {
+                return self.validate_selected(py, selector, selected.bind(py), input, state, explanation);
             }
-            return Ok(best_match);
         }
+        match self.mode {
+            UnionMode::Smart | UnionMode::Exclusive => self.validate_smart(py, input, state, false, explanation),
+            UnionMode::LeftToRight => self.validate_left_to_right(py, input, state),
+            UnionMode::StrictThenLax => self.validate_strict_then_lax(py, input, state),
+        }
+    }
 
-        Err(errors.into_val_error(input))
+    fn ambiguous_error<'py>(&self, input: &(impl Input<'py> + ?Sized), matched: &[&str]) -> ValError {
+        match self.custom_error {
+            Some(ref custom_error) => custom_error.as_val_error(input),
+            None => ValError::new(
+                ErrorType::UnionAmbiguous {
+                    matched_choices: matched.join(", "),
+                    context: None,
+                },
+                input,
+            ),
+        }
     }
 
     fn validate_left_to_right<'py>(
@@ -191,29 +853,44 @@ impl UnionValidator {
         py: Python<'py>,
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
-    ) -> ValResult<PyObject> {
+    ) -> ValResult<(PyObject, usize)> {
         let old_strict = state.strict; // NEW: Save original strict mode
         let mut errors = MaybeErrors::new(self.custom_error.as_ref());
+        let input_kind = self.input_filter(input);
+        let input_type = self.coercion_input_type(input);
 
-        for (validator, label) in &self.choices {
-            state.strict = self.effective_strict(state, validator); // NEW: Apply field-level strictness
-            match validator.validate(py, input, state) {
-                Err(ValError::LineErrors(lines)) => errors.push(validator, label.as_deref(), lines),
+        for (index, choice) in self.choices.iter().enumerate() {
+            if !choice.input_kinds.intersects(input_kind) {
+                continue;
+            }
+            // NEW: Apply field-level strictness
+            state.strict =
+                self.coercion_denied(choice, input_type.as_deref()) || self.effective_strict(state, choice);
+            match choice.validate(py, input, state) {
+                Err(ValError::LineErrors(lines)) => errors.push(choice, lines),
                 otherwise => {
                     state.strict = old_strict; // NEW: Restore before return
-                    return otherwise;
+                    let value = otherwise?;
+                    choice.warn_if_deprecated(py)?;
+                    return Ok((value, index));
                 },
             }
             state.strict = old_strict; // NEW: Restore after each iteration
         }
 
-        Err(errors.into_val_error(input))
+        Err(errors.into_val_error(input, self.error_mode))
     }
 }
 
 impl PyGcTraverse for UnionValidator {
     fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
-        self.choices.iter().try_for_each(|(v, _)| v.py_gc_traverse(visit))?;
+        self.choices.iter().try_for_each(|choice| choice.validator.py_gc_traverse(visit))?;
+        if let Some(selector) = &self.selector {
+            selector.func.py_gc_traverse(visit)?;
+        }
+        if let Some(explain) = &self.explain {
+            explain.py_gc_traverse(visit)?;
+        }
         Ok(())
     }
 }
@@ -225,9 +902,26 @@ impl Validator for UnionValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        match self.mode {
-            UnionMode::Smart => self.validate_smart(py, input, state),
-            UnionMode::LeftToRight => self.validate_left_to_right(py, input, state),
+        let smart = matches!(self.mode, UnionMode::Smart | UnionMode::Exclusive);
+        let mut explanation = match self.explain {
+            Some(_) if smart => Some(SmartExplanation::new(self.choices.len())),
+            _ => None,
+        };
+
+        let result = with_strict_profile(state, self.strict_profile.as_ref(), |state| {
+            self.validate_choices(py, input, state, explanation.as_mut())
+        });
+
+        if let (Some(explain), Some(mut explanation)) = (&self.explain, explanation) {
+            explanation.selected = result.as_ref().ok().map(|(_, index)| *index);
+            explain.call1(py, (explanation.to_py(py, &self.choices)?,))?;
+        }
+
+        let (value, index) = result?;
+        if self.return_label {
+            Ok((self.choices[index].name(), value).into_pyobject(py)?.into_any().unbind())
+        } else {
+            Ok(value)
         }
     }
 
@@ -236,12 +930,329 @@ impl Validator for UnionValidator {
     }
 }
 
+/// Set of input kinds, used to skip union choices that could never accept a given input.
+///
+/// The sets worked out for choices are deliberately generous (they assume lax mode), any schema we
+/// don't know about accepts everything.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct InputKinds(u8);
+
+impl InputKinds {
+    const NONE: Self = Self(1 << 0);
+    /// `bool`, `int` and `float`
+    const NUMBER: Self = Self(1 << 1);
+    const STR: Self = Self(1 << 2);
+    const BYTES: Self = Self(1 << 3);
+    const DICT: Self = Self(1 << 4);
+    /// `list`, `tuple`, `set` and `frozenset`
+    const LIST: Self = Self(1 << 5);
+    /// instances of any other type, including subclasses of the types above
+    const OTHER: Self = Self(1 << 6);
+    const ALL: Self = Self(u8::MAX);
+
+    const fn with(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    fn accepted_by(schema: &Bound<'_, PyDict>, config: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
+        let py = schema.py();
+        let schema_type: Bound<'_, PyString> = schema.get_as_req(intern!(py, "type"))?;
+        let scalar = Self::STR.with(Self::BYTES).with(Self::OTHER);
+        let kinds = match schema_type.to_str()? {
+            "none" => Self::NONE,
+            "int" | "float" | "decimal" | "bool" => scalar.with(Self::NUMBER),
+            "str" => {
+                let key = intern!(py, "coerce_numbers_to_str");
+                if schema_or_config(schema, config, key, key)?.unwrap_or(false) {
+                    scalar.with(Self::NUMBER)
+                } else {
+                    scalar
+                }
+            }
+            "bytes" => scalar,
+            "list" | "tuple" | "set" | "frozenset" => Self::LIST.with(Self::OTHER),
+            "dict" | "typed-dict" | "dataclass" => Self::DICT.with(Self::OTHER),
+            "model" => {
+                if schema.get_as(intern!(py, "root_model"))?.unwrap_or(false) {
+                    // root models accept whatever their root type accepts, as well as instances
+                    let inner: Bound<'_, PyDict> = schema.get_as_req(intern!(py, "schema"))?;
+                    Self::accepted_by(&inner, config)?.with(Self::OTHER)
+                } else {
+                    Self::DICT.with(Self::OTHER)
+                }
+            }
+            "nullable" => Self::NONE.with(Self::accepted_by(&schema.get_as_req(intern!(py, "schema"))?, config)?),
+            "default" => Self::accepted_by(&schema.get_as_req(intern!(py, "schema"))?, config)?,
+            _ => Self::ALL,
+        };
+        Ok(kinds)
+    }
+
+    fn of_input<'py>(input: &(impl Input<'py> + ?Sized)) -> Self {
+        if input.is_none() {
+            return Self::NONE;
+        }
+        // JSON and other non-python inputs aren't classified, every choice is tried for them
+        let Some(obj) = input.as_python() else {
+            return Self::ALL;
+        };
+        if obj.is_exact_instance_of::<PyBool>()
+            || obj.is_exact_instance_of::<PyInt>()
+            || obj.is_exact_instance_of::<PyFloat>()
+        {
+            Self::NUMBER
+        } else if obj.is_exact_instance_of::<PyString>() {
+            Self::STR
+        } else if obj.is_exact_instance_of::<PyBytes>() {
+            Self::BYTES
+        } else if obj.is_exact_instance_of::<PyDict>() {
+            Self::DICT
+        } else if obj.is_exact_instance_of::<PyList>()
+            || obj.is_exact_instance_of::<PyTuple>()
+            || obj.is_exact_instance_of::<PySet>()
+            || obj.is_exact_instance_of::<PyFrozenSet>()
+        {
+            Self::LIST
+        } else {
+            Self::OTHER
+        }
+    }
+}
+
+/// How well a successful choice matched, used to pick between choices that all succeeded.
+#[derive(Debug, Clone, Copy)]
+struct MatchRank {
+    exactness: Exactness,
+    fields_set_count: Option<usize>,
+    /// lax coercions the choice needed, weighted by how lossy each one was, as added up in
+    /// `state.coercion_cost` by the validators doing the coercing
+    coercion_cost: u32,
+}
+
+impl MatchRank {
+    fn from_state(state: &ValidationState<'_, '_>) -> Self {
+        debug_assert_ne!(state.exactness, None);
+        Self {
+            exactness: state.exactness.unwrap_or(Exactness::Lax),
+            fields_set_count: state.fields_set_count,
+            coercion_cost: state.coercion_cost,
+        }
+    }
+
+    /// Rank against the current best: more fields set wins when both choices report a count,
+    /// otherwise the higher exactness wins and then the cheaper coercions.
+    fn compare(&self, current: &Self) -> Ordering {
+        match (self.fields_set_count, current.fields_set_count) {
+            (Some(new_count), Some(cur_count)) if new_count != cur_count => new_count.cmp(&cur_count),
+            _ => self
+                .exactness
+                .cmp(&current.exactness)
+                .then_with(|| current.coercion_cost.cmp(&self.coercion_cost)),
+        }
+    }
+
+    /// Pass the chosen match's rank up to the union's parent.
+    fn apply(&self, state: &mut ValidationState<'_, '_>) {
+        state.floor_exactness(self.exactness);
+        if let Some(count) = self.fields_set_count {
+            state.add_fields_set(count);
+        }
+        state.add_coercion_cost(self.coercion_cost);
+    }
+}
+
+/// The best of the choices that have succeeded so far in a smart-mode run, along with the state
+/// saved before the run, so `UnionValidator` and the tagged union's `'smart'` fallback rank their
+/// choices the same way.
+struct BestMatch {
+    old_exactness: Option<Exactness>,
+    old_fields_set_count: Option<usize>,
+    old_coercion_cost: u32,
+    best: Option<(PyObject, MatchRank, usize)>,
+    /// indexes of every choice whose rank equals the best so far, only used to report ambiguity
+    tied: SmallVec<[usize; SMALL_UNION_THRESHOLD]>,
+}
+
+impl BestMatch {
+    fn new(state: &ValidationState<'_, '_>) -> Self {
+        Self {
+            old_exactness: state.exactness,
+            old_fields_set_count: state.fields_set_count,
+            old_coercion_cost: state.coercion_cost,
+            best: None,
+            tied: SmallVec::new(),
+        }
+    }
+
+    /// Reset the state so the next choice's rank is measured on its own.
+    fn start_choice(&self, state: &mut ValidationState<'_, '_>) {
+        state.exactness = Some(Exactness::Exact);
+        state.fields_set_count = None;
+        state.coercion_cost = 0;
+    }
+
+    /// Whether the choice that just succeeded matched exactly, which no other choice can beat.
+    fn is_exact(state: &ValidationState<'_, '_>) -> bool {
+        matches!((state.exactness, state.fields_set_count), (Some(Exactness::Exact), None))
+    }
+
+    /// Rank the choice that just succeeded against the best so far, `prefers` decides whether it
+    /// replaces an equally ranked best, given that choice's index.
+    fn push(
+        &mut self,
+        state: &ValidationState<'_, '_>,
+        output: PyObject,
+        index: usize,
+        prefers: impl FnOnce(usize) -> bool,
+    ) {
+        let new_rank = MatchRank::from_state(state);
+        let ordering = self
+            .best
+            .as_ref()
+            .map_or(Ordering::Greater, |(_, cur_rank, _)| new_rank.compare(cur_rank));
+        if ordering == Ordering::Greater {
+            self.tied.clear();
+        }
+        if ordering != Ordering::Less {
+            self.tied.push(index);
+        }
+
+        let new_is_best = match (ordering, &self.best) {
+            (Ordering::Equal, Some((_, _, cur_index))) => prefers(*cur_index),
+            (ordering, _) => ordering == Ordering::Greater,
+        };
+        if new_is_best {
+            self.best = Some((output, new_rank, index));
+        }
+    }
+
+    fn is_found(&self) -> bool {
+        self.best.is_some()
+    }
+
+    /// Put back the state saved when the run started.
+    fn restore(&self, state: &mut ValidationState<'_, '_>) {
+        state.exactness = self.old_exactness;
+        state.fields_set_count = self.old_fields_set_count;
+        state.coercion_cost = self.old_coercion_cost;
+    }
+
+    /// Restore the state and pass the best match's rank on to it, returning the best match, its
+    /// index and the indexes of every choice tied with it.
+    fn finish(
+        self,
+        state: &mut ValidationState<'_, '_>,
+    ) -> Option<(PyObject, usize, SmallVec<[usize; SMALL_UNION_THRESHOLD]>)> {
+        self.restore(state);
+        let (output, rank, index) = self.best?;
+        rank.apply(state);
+        Some((output, index, self.tied))
+    }
+}
+
+/// What happened when a single choice was tried in smart mode.
+#[derive(Debug)]
+struct ChoiceOutcome {
+    success: bool,
+    exactness: Option<Exactness>,
+    fields_set_count: Option<usize>,
+    coercion_cost: Option<u32>,
+    effective_strict: bool,
+}
+
+/// Record of a smart-mode run, only collected when the union schema sets `explain`.
+#[derive(Debug)]
+struct SmartExplanation {
+    /// indexed like the union's choices, `None` for choices that weren't tried: those the input
+    /// filter ruled out, those after an exact match, and all but the one a `selector` picked
+    outcomes: Vec<Option<ChoiceOutcome>>,
+    selected: Option<usize>,
+}
+
+impl SmartExplanation {
+    fn new(choice_count: usize) -> Self {
+        Self {
+            outcomes: (0..choice_count).map(|_| None).collect(),
+            selected: None,
+        }
+    }
+
+    fn record(
+        &mut self,
+        index: usize,
+        success: bool,
+        state: &ValidationState<'_, '_>,
+        effective_strict: bool,
+    ) {
+        // exactness, fields_set_count and coercion_cost are only meaningful when the choice succeeded
+        self.outcomes[index] = Some(ChoiceOutcome {
+            success,
+            exactness: if success { state.exactness } else { None },
+            fields_set_count: if success { state.fields_set_count } else { None },
+            coercion_cost: if success { Some(state.coercion_cost) } else { None },
+            effective_strict,
+        });
+    }
+
+    fn to_py<'py>(&self, py: Python<'py>, choices: &[UnionChoice]) -> PyResult<Bound<'py, PyDict>> {
+        let choice_name = |index: usize| choices[index].name();
+
+        let outcomes = PyList::empty(py);
+        for (index, outcome) in self.outcomes.iter().enumerate() {
+            let item = PyDict::new(py);
+            item.set_item(intern!(py, "choice"), choice_name(index))?;
+            item.set_item(intern!(py, "skipped"), outcome.is_none())?;
+            if let Some(outcome) = outcome {
+                item.set_item(intern!(py, "success"), outcome.success)?;
+                item.set_item(intern!(py, "exactness"), outcome.exactness.map(exactness_str))?;
+                item.set_item(intern!(py, "fields_set_count"), outcome.fields_set_count)?;
+                item.set_item(intern!(py, "coercion_cost"), outcome.coercion_cost)?;
+                item.set_item(intern!(py, "effective_strict"), outcome.effective_strict)?;
+            }
+            outcomes.append(item)?;
+        }
+
+        let explanation = PyDict::new(py);
+        explanation.set_item(intern!(py, "selected"), self.selected.map(choice_name))?;
+        explanation.set_item(intern!(py, "choices"), outcomes)?;
+        Ok(explanation)
+    }
+}
+
+fn exactness_str(exactness: Exactness) -> &'static str {
+    match exactness {
+        Exactness::Lax => "lax",
+        Exactness::Strict => "strict",
+        Exactness::Exact => "exact",
+    }
+}
+
 struct ChoiceLineErrors<'a> {
-    choice: &'a CombinedValidator,
-    label: Option<&'a str>,
+    choice: &'a UnionChoice,
     line_errors: Vec<ValLineError>,
 }
 
+impl ChoiceLineErrors<'_> {
+    /// How close the choice came to matching: errors deeper into the input mean validation got
+    /// further before failing, and at the same depth fewer errors is closer.
+    fn closeness(&self) -> (usize, Reverse<usize>) {
+        let depth = self
+            .line_errors
+            .iter()
+            .map(|line_error| match &line_error.location {
+                Location::Empty => 0,
+                Location::List(items) => items.len(),
+            })
+            .max()
+            .unwrap_or(0);
+        (depth, Reverse(self.line_errors.len()))
+    }
+}
+
 enum MaybeErrors<'a> {
     Custom(&'a CustomError),
     Errors(SmallVec<[ChoiceLineErrors<'a>; SMALL_UNION_THRESHOLD]>),
@@ -255,47 +1266,248 @@ impl<'a> MaybeErrors<'a> {
         }
     }
 
-    fn push(&mut self, choice: &'a CombinedValidator, label: Option<&'a str>, line_errors: Vec<ValLineError>) {
+    fn push(&mut self, choice: &'a UnionChoice, line_errors: Vec<ValLineError>) {
         match self {
             Self::Custom(_) => {}
-            Self::Errors(errors) => errors.push(ChoiceLineErrors {
-                choice,
-                label,
-                line_errors,
-            }),
+            Self::Errors(errors) => errors.push(ChoiceLineErrors { choice, line_errors }),
         }
     }
 
-    fn into_val_error(self, input: impl ToErrorValue) -> ValError {
+    fn into_val_error(self, input: impl ToErrorValue, error_mode: UnionErrorMode) -> ValError {
         match self {
             Self::Custom(custom_error) => custom_error.as_val_error(input),
-            Self::Errors(errors) => ValError::LineErrors(
-                errors
-                    .into_iter()
-                    .flat_map(
-                        |ChoiceLineErrors {
-                             choice,
-                             label,
-                             line_errors,
-                         }| {
-                            line_errors.into_iter().map(move |err| {
-                                let case_label = label.unwrap_or(choice.get_name());
-                                err.with_outer_location(case_label)
-                            })
-                        },
-                    )
-                    .collect(),
+            Self::Errors(mut errors) => {
+                if let UnionErrorMode::Closest = error_mode {
+                    let closest = errors.iter().map(ChoiceLineErrors::closeness).max();
+                    errors.retain(|choice_errors| Some(choice_errors.closeness()) == closest);
+                }
+                ValError::LineErrors(
+                    errors
+                        .into_iter()
+                        .flat_map(|ChoiceLineErrors { choice, line_errors }| {
+                            line_errors
+                                .into_iter()
+                                .map(move |err| err.with_outer_location(choice.name()))
+                        })
+                        .collect(),
+                )
+            }
+        }
+    }
+}
+
+/// How string tags are compared with the tags of a tagged union's choices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TagMatching {
+    Exact,
+    CaseInsensitive,
+    /// case insensitive, ignoring surrounding whitespace and any `-` or `_`, so `"CreditCard"`
+    /// matches `"credit_card"`
+    Normalized,
+}
+
+impl FromStr for TagMatching {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "exact" => Ok(Self::Exact),
+            "case_insensitive" => Ok(Self::CaseInsensitive),
+            "normalized" => Ok(Self::Normalized),
+            s => py_schema_err!(
+                "Invalid tag matching: `{}`, expected `exact`, `case_insensitive` or `normalized`",
+                s
+            ),
+        }
+    }
+}
+
+impl TagMatching {
+    fn normalize(self, tag: &str) -> String {
+        match self {
+            Self::Exact => tag.to_string(),
+            Self::CaseInsensitive => tag.to_lowercase(),
+            Self::Normalized => tag
+                .trim()
+                .chars()
+                .filter(|c| !matches!(c, '-' | '_'))
+                .flat_map(char::to_lowercase)
+                .collect(),
+        }
+    }
+}
+
+/// Matches tags which aren't one of a tagged union's literal tags to a choice, so e.g. every `version`
+/// from 4 up can select the same choice.
+#[derive(Debug)]
+struct TagPredicate {
+    kind: TagPredicateKind,
+    /// tag of the choice selected when the predicate matches
+    choice_tag: PyObject,
+}
+
+#[derive(Debug)]
+enum TagPredicateKind {
+    /// int tags between the bounds, inclusive, either of which can be left out
+    Range { min: Option<i64>, max: Option<i64> },
+    /// string tags starting with the prefix
+    Prefix(String),
+    /// string tags containing a match for the regex
+    Pattern(Regex),
+}
+
+impl TagPredicate {
+    fn build(py: Python, predicate: &Bound<'_, PyDict>, lookup: &LiteralLookup<CombinedValidator>) -> PyResult<Self> {
+        let choice_tag: Bound<'_, PyAny> = predicate.get_as_req(intern!(py, "tag"))?;
+        if !matches!(lookup.validate(py, &choice_tag), Ok(Some(_))) {
+            return py_schema_err!("Tag predicate for `{}`, which isn't a tag of any choice", choice_tag.repr()?);
+        }
+
+        let ge: Option<i64> = predicate.get_as(intern!(py, "ge"))?;
+        let gt: Option<i64> = predicate.get_as(intern!(py, "gt"))?;
+        let le: Option<i64> = predicate.get_as(intern!(py, "le"))?;
+        let lt: Option<i64> = predicate.get_as(intern!(py, "lt"))?;
+        let min = [ge, gt.map(|gt| gt.saturating_add(1))].into_iter().flatten().max();
+        let max = [le, lt.map(|lt| lt.saturating_sub(1))].into_iter().flatten().min();
+        if let (Some(min), Some(max)) = (min, max) {
+            if min > max {
+                return py_schema_err!("Tag predicate range for `{}` can't match any tag", choice_tag.repr()?);
+            }
+        }
+        let prefix: Option<String> = predicate.get_as(intern!(py, "prefix"))?;
+        let pattern: Option<String> = predicate.get_as(intern!(py, "pattern"))?;
+
+        let kind = match (min.is_some() || max.is_some(), prefix, pattern) {
+            (true, None, None) => TagPredicateKind::Range { min, max },
+            (false, Some(prefix), None) => TagPredicateKind::Prefix(prefix),
+            (false, None, Some(pattern)) => match Regex::new(&pattern) {
+                Ok(regex) => TagPredicateKind::Pattern(regex),
+                Err(err) => return py_schema_err!("Invalid tag pattern `{}`: {}", pattern, err),
+            },
+            _ => return py_schema_err!("Tag predicate must have exactly one of a range, `prefix` or `pattern`"),
+        };
+        Ok(Self {
+            kind,
+            choice_tag: choice_tag.unbind(),
+        })
+    }
+
+    /// How exactly `tag` matches, `None` if it doesn't. In lax mode a string holding an int can match a range.
+    fn matches(&self, tag: &Bound<'_, PyAny>, strict: bool) -> Option<Exactness> {
+        match &self.kind {
+            TagPredicateKind::Range { min, max } => {
+                let (value, exactness) = if tag.is_instance_of::<PyInt>() && !tag.is_instance_of::<PyBool>() {
+                    (tag.extract::<i64>().ok()?, Exactness::Exact)
+                } else if strict {
+                    return None;
+                } else {
+                    let tag = tag.downcast::<PyString>().ok()?;
+                    (tag.to_str().ok()?.trim().parse::<i64>().ok()?, Exactness::Lax)
+                };
+                let in_range = min.map_or(true, |min| value >= min) && max.map_or(true, |max| value <= max);
+                in_range.then_some(exactness)
+            }
+            TagPredicateKind::Prefix(prefix) => {
+                let tag = tag.downcast::<PyString>().ok()?;
+                tag.to_str().ok()?.starts_with(prefix.as_str()).then_some(Exactness::Exact)
+            }
+            TagPredicateKind::Pattern(regex) => {
+                let tag = tag.downcast::<PyString>().ok()?;
+                regex.is_match(tag.to_str().ok()?).then_some(Exactness::Exact)
+            }
+        }
+    }
+}
+
+impl PyGcTraverse for TagPredicate {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        visit.call(&self.choice_tag)
+    }
+}
+
+/// How a tagged union finds the tag of an input.
+#[derive(Debug)]
+enum TagDiscriminator {
+    Single(Discriminator),
+    /// a tuple of lookup keys, the tag is the tuple of their values so choices are keyed by tuples,
+    /// e.g. `("kind", "version")` and `{("cat", 1): ..., ("cat", 2): ...}`. `tag_matching`, lax tag
+    /// coercion and `tag_predicates` only look at string and int tags, so they don't apply to tuples,
+    /// only aliases do.
+    Composite(Vec<LookupKey>),
+}
+
+impl TagDiscriminator {
+    fn new(py: Python, raw: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let Ok(keys) = raw.downcast::<PyTuple>() else {
+            return Ok(Self::Single(Discriminator::new(py, raw)?));
+        };
+        if keys.is_empty() {
+            return py_schema_err!("Composite discriminator must have at least one key");
+        }
+        let keys = keys
+            .iter()
+            .map(|key| LookupKey::from_py(py, &key, None))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(Self::Composite(keys))
+    }
+
+    /// Check a choice's tag could ever be found: with a composite discriminator it has to be a tuple
+    /// with a value for each key.
+    fn check_tag(&self, tag: &Bound<'_, PyAny>) -> PyResult<()> {
+        let Self::Composite(keys) = self else {
+            return Ok(());
+        };
+        match tag.downcast::<PyTuple>() {
+            Ok(tag) if tag.len() == keys.len() => Ok(()),
+            _ => py_schema_err!(
+                "Tag `{}` must be a tuple of {} values to match the composite discriminator",
+                tag.repr()?,
+                keys.len()
             ),
         }
     }
+
+    fn to_string_py(&self, py: Python) -> PyResult<String> {
+        match self {
+            Self::Single(discriminator) => discriminator.to_string_py(py),
+            Self::Composite(keys) => {
+                let keys = keys.iter().map(ToString::to_string).collect::<Vec<_>>();
+                Ok(format!("({})", keys.join(", ")))
+            }
+        }
+    }
+}
+
+impl PyGcTraverse for TagDiscriminator {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        match self {
+            Self::Single(discriminator) => discriminator.py_gc_traverse(visit),
+            Self::Composite(_) => Ok(()),
+        }
+    }
 }
 
 #[derive(Debug)]
 pub struct TaggedUnionValidator {
-    discriminator: Discriminator,
+    discriminator: TagDiscriminator,
     lookup: LiteralLookup<CombinedValidator>,
     from_attributes: bool,
     custom_error: Option<CustomError>,
+    /// validates inputs whose tag is missing or not one of the choices' tags, instead of erroring
+    fallback: Option<TagFallback>,
+    /// alias tags, mapped to the tag of their choice
+    aliases: Option<LiteralLookup<PyObject>>,
+    tag_matching: TagMatching,
+    /// normalized forms of every string tag and alias, mapped to the tag of their choice, only set
+    /// when `tag_matching` isn't exact
+    normalized_tags: Option<LiteralLookup<PyObject>>,
+    /// tags as they're written when they arrive as strings or as an enum's value, e.g. `"1"` for `1`,
+    /// mapped to the tag of their choice, only used in lax mode
+    lax_tags: Option<LiteralLookup<PyObject>>,
+    /// strictness for matching tags, `None` inherits from the parent
+    strict: Option<bool>,
+    /// tried in order when the tag isn't one of the literal tags
+    tag_predicates: Vec<TagPredicate>,
     tags_repr: String,
     discriminator_repr: String,
     name: String,
@@ -310,7 +1522,7 @@ impl BuildValidator for TaggedUnionValidator {
         definitions: &mut DefinitionsBuilder<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
         let py = schema.py();
-        let discriminator = Discriminator::new(py, &schema.get_as_req(intern!(py, "discriminator"))?)?;
+        let discriminator = TagDiscriminator::new(py, &schema.get_as_req(intern!(py, "discriminator"))?)?;
         let discriminator_repr = discriminator.to_string_py(py)?;
 
         let choices = PyDict::new(py);
@@ -318,8 +1530,14 @@ impl BuildValidator for TaggedUnionValidator {
         let mut descr = String::with_capacity(50);
         let mut first = true;
         let schema_choices: Bound<PyDict> = schema.get_as_req(intern!(py, "choices"))?;
+        let fallback = match schema.get_as::<Bound<'_, PyAny>>(intern!(py, "fallback"))? {
+            Some(fallback) => Some(TagFallback::build(&fallback, config, definitions)?),
+            None => None,
+        };
         let mut lookup_map = Vec::with_capacity(choices.len());
+        let mut tags = Vec::with_capacity(choices.len());
         for (choice_key, choice_schema) in schema_choices {
+            discriminator.check_tag(&choice_key)?;
             let validator = build_validator(&choice_schema, config, definitions)?;
             let tag_repr = choice_key.repr()?.to_string();
             if first {
@@ -331,19 +1549,81 @@ impl BuildValidator for TaggedUnionValidator {
                 // no spaces in get_name() output to make loc easy to read
                 write!(descr, ",{}", validator.get_name()).unwrap();
             }
+            tags.push((choice_key.clone(), choice_key.clone().unbind()));
             lookup_map.push((choice_key, validator));
         }
 
         let lookup = LiteralLookup::new(py, lookup_map.into_iter())?;
 
+        let mut alias_map = Vec::new();
+        if let Some(tag_aliases) = schema.get_as::<Bound<'_, PyDict>>(intern!(py, "tag_aliases"))? {
+            for (tag, aliases) in tag_aliases {
+                if !matches!(lookup.validate(py, &tag), Ok(Some(_))) {
+                    return py_schema_err!("Aliases given for `{}`, which isn't a tag of any choice", tag.repr()?);
+                }
+                for alias in aliases.downcast::<PyList>()? {
+                    discriminator.check_tag(&alias)?;
+                    // aliases are checked before tags, so one matching a tag would hide that choice
+                    if matches!(lookup.validate(py, &alias), Ok(Some(_))) {
+                        return py_schema_err!("Alias `{}` is already the tag of a choice", alias.repr()?);
+                    }
+                    // an alias under two tags would route to whichever choice was listed last
+                    let listed = alias_map.iter().find(|(other, _)| other.eq(&alias).unwrap_or(false));
+                    if let Some((_, other_tag)) = listed {
+                        if other_tag.bind(py).eq(&tag)? {
+                            continue;
+                        }
+                        return py_schema_err!(
+                            "Alias `{}` is given for both `{}` and `{}`",
+                            alias.repr()?,
+                            other_tag.bind(py).repr()?,
+                            tag.repr()?
+                        );
+                    }
+                    alias_map.push((alias, tag.clone().unbind()));
+                }
+            }
+        }
+        tags.extend(alias_map.iter().map(|(alias, tag)| (alias.clone(), tag.clone_ref(py))));
+        let aliases = if alias_map.is_empty() {
+            None
+        } else {
+            Some(LiteralLookup::new(py, alias_map.into_iter())?)
+        };
+
+        let tag_matching = schema
+            .get_as::<Bound<'_, PyString>>(intern!(py, "tag_matching"))?
+            .map_or(Ok(TagMatching::Exact), |matching| matching.to_str().and_then(TagMatching::from_str))?;
+        let lax_tags = build_lax_tags(py, &lookup, &tags)?;
+        let tag_predicates = match schema.get_as::<Bound<'_, PyList>>(intern!(py, "tag_predicates"))? {
+            Some(predicates) => predicates
+                .iter()
+                .map(|predicate| TagPredicate::build(py, predicate.downcast()?, &lookup))
+                .collect::<PyResult<Vec<_>>>()?,
+            None => Vec::new(),
+        };
+        let normalized_tags = match tag_matching {
+            TagMatching::Exact => None,
+            _ => Some(build_normalized_tags(py, tag_matching, tags)?),
+        };
+
         let key = intern!(py, "from_attributes");
         let from_attributes = schema_or_config(schema, config, key, key)?.unwrap_or(true);
+        let key = intern!(py, "strict");
+        let strict = schema_or_config(schema, config, key, key)?;
 
         Ok(Self {
             discriminator,
             lookup,
             from_attributes,
             custom_error: CustomError::build(schema, config, definitions)?,
+            fallback,
+            aliases,
+            tag_matching,
+            normalized_tags,
+            lax_tags,
+            strict,
+            tag_predicates,
             tags_repr,
             discriminator_repr,
             name: format!("{}[{descr}]", Self::EXPECTED_TYPE),
@@ -352,7 +1632,115 @@ impl BuildValidator for TaggedUnionValidator {
     }
 }
 
-impl_py_gc_traverse!(TaggedUnionValidator { discriminator, lookup });
+/// Map the normalized form of every string tag or alias to the tag of its choice, failing if two choices' tags
+/// normalize to the same string.
+fn build_normalized_tags<'py>(
+    py: Python<'py>,
+    tag_matching: TagMatching,
+    tags: Vec<(Bound<'py, PyAny>, PyObject)>,
+) -> PyResult<LiteralLookup<PyObject>> {
+    let mut normalized_tags: Vec<(Bound<'py, PyAny>, PyObject)> = Vec::with_capacity(tags.len());
+    for (tag, choice_tag) in tags {
+        let Ok(tag) = tag.downcast::<PyString>() else {
+            continue;
+        };
+        let normalized = PyString::new(py, &tag_matching.normalize(tag.to_str()?)).into_any();
+        // an alias can normalize to the same string as its own choice's tag, that's fine
+        if let Some((_, other_tag)) = normalized_tags.iter().find(|(other, _)| other.eq(&normalized).unwrap_or(false)) {
+            if !other_tag.bind(py).eq(&choice_tag)? {
+                return py_schema_err!(
+                    "Tags `{}` and `{}` are the same once normalized",
+                    other_tag.bind(py).repr()?,
+                    choice_tag.bind(py).repr()?
+                );
+            }
+            continue;
+        }
+        normalized_tags.push((normalized, choice_tag));
+    }
+    LiteralLookup::new(py, normalized_tags.into_iter())
+}
+
+/// Map the forms a tag or alias can be coerced from to the tag of its choice: the string form of int tags, and the
+/// value of enum member tags. Forms which are already a tag, or which are shared with an earlier choice, are left
+/// out.
+fn build_lax_tags<'py>(
+    py: Python<'py>,
+    lookup: &LiteralLookup<CombinedValidator>,
+    tags: &[(Bound<'py, PyAny>, PyObject)],
+) -> PyResult<Option<LiteralLookup<PyObject>>> {
+    let enum_type = py.import(intern!(py, "enum"))?.getattr(intern!(py, "Enum"))?;
+    let mut lax_tags: Vec<(Bound<'py, PyAny>, PyObject)> = Vec::new();
+    for (tag, choice_tag) in tags {
+        let mut forms = Vec::with_capacity(2);
+        let value = if tag.is_instance(&enum_type)? {
+            let value = tag.getattr(intern!(py, "value"))?;
+            forms.push(value.clone());
+            value
+        } else {
+            tag.clone()
+        };
+        if value.is_instance_of::<PyInt>() && !value.is_instance_of::<PyBool>() {
+            forms.push(value.str()?.into_any());
+        }
+        for form in forms {
+            let is_tag = matches!(lookup.validate(py, &form), Ok(Some(_)));
+            if is_tag || lax_tags.iter().any(|(other, _)| other.eq(&form).unwrap_or(false)) {
+                continue;
+            }
+            lax_tags.push((form, choice_tag.clone_ref(py)));
+        }
+    }
+    if lax_tags.is_empty() {
+        Ok(None)
+    } else {
+        LiteralLookup::new(py, lax_tags.into_iter()).map(Some)
+    }
+}
+
+/// What a tagged union validates with when the tag is missing or unknown.
+#[derive(Debug)]
+enum TagFallback {
+    Validator(Box<CombinedValidator>),
+    /// smart union matching over every choice, using the choices' own validators
+    Smart,
+}
+
+impl TagFallback {
+    /// Build from either a schema or `'smart'`.
+    fn build(
+        fallback: &Bound<'_, PyAny>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<Self> {
+        let Ok(mode) = fallback.downcast::<PyString>() else {
+            return Ok(Self::Validator(Box::new(build_validator(fallback, config, definitions)?)));
+        };
+        match mode.to_str()? {
+            "smart" => Ok(Self::Smart),
+            mode => py_schema_err!("Invalid tagged union fallback: `{}`, expected `smart` or a schema", mode),
+        }
+    }
+}
+
+impl PyGcTraverse for TagFallback {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        match self {
+            Self::Validator(validator) => validator.py_gc_traverse(visit),
+            Self::Smart => Ok(()),
+        }
+    }
+}
+
+impl_py_gc_traverse!(TaggedUnionValidator {
+    discriminator,
+    lookup,
+    fallback,
+    aliases,
+    normalized_tags,
+    lax_tags,
+    tag_predicates,
+});
 
 impl Validator for TaggedUnionValidator {
     fn validate<'py>(
@@ -362,25 +1750,38 @@ impl Validator for TaggedUnionValidator {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         match &self.discriminator {
-            Discriminator::LookupKey(lookup_key) => {
+            TagDiscriminator::Single(Discriminator::LookupKey(lookup_key)) => {
                 let from_attributes = state.extra().from_attributes.unwrap_or(self.from_attributes);
                 let dict = input.validate_model_fields(state.strict_or(false), from_attributes)?;
                 // note this methods returns PyResult<Option<(data, data)>>, the outer Err is just for
                 // errors when getting attributes which should be "raised"
                 let tag = match dict.get_item(lookup_key)? {
                     Some((_, value)) => value,
-                    None => return Err(self.tag_not_found(input)),
+                    None => return self.tag_not_found(py, input, state),
                 };
                 self.find_call_validator(py, &tag.borrow_input().to_object(py)?, input, state)
             }
-            Discriminator::Function(func) => {
+            TagDiscriminator::Single(Discriminator::Function(func)) => {
                 let tag: Py<PyAny> = func.call1(py, (input.to_object(py)?,))?;
                 if tag.is_none(py) {
-                    Err(self.tag_not_found(input))
+                    self.tag_not_found(py, input, state)
                 } else {
                     self.find_call_validator(py, tag.bind(py), input, state)
                 }
             }
+            TagDiscriminator::Composite(lookup_keys) => {
+                let from_attributes = state.extra().from_attributes.unwrap_or(self.from_attributes);
+                let dict = input.validate_model_fields(state.strict_or(false), from_attributes)?;
+                let mut tag = Vec::with_capacity(lookup_keys.len());
+                for lookup_key in lookup_keys {
+                    match dict.get_item(lookup_key)? {
+                        Some((_, value)) => tag.push(value.borrow_input().to_object(py)?),
+                        // every key has to be present to make up the tag
+                        None => return self.tag_not_found(py, input, state),
+                    }
+                }
+                self.find_call_validator(py, PyTuple::new(py, tag)?.as_any(), input, state)
+            }
         }
     }
 
@@ -397,12 +1798,16 @@ impl TaggedUnionValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        if let Ok(Some((tag, validator))) = self.lookup.validate(py, tag) {
+        let choice_tag = self.choice_tag(py, tag, state);
+        if let Ok(Some((tag, validator))) = self.lookup.validate(py, &choice_tag) {
             return match validator.validate(py, input, state) {
                 Ok(res) => Ok(res),
                 Err(err) => Err(err.with_outer_location(tag)),
             };
         }
+        if let Some(fallback) = &self.fallback {
+            return self.validate_fallback(py, fallback, input, state);
+        }
         match self.custom_error {
             Some(ref custom_error) => Err(custom_error.as_val_error(input)),
             None => Err(ValError::new(
@@ -417,8 +1822,121 @@ impl TaggedUnionValidator {
         }
     }
 
-    fn tag_not_found<'py>(&self, input: &(impl Input<'py> + ?Sized)) -> ValError {
-        match self.custom_error {
+    /// Resolve an alias, a string tag written differently when matching isn't exact, a tag that can be
+    /// coerced in lax mode, or one matching a predicate, to the tag of its choice. Any other tag is
+    /// returned unchanged.
+    fn choice_tag<'py>(
+        &self,
+        py: Python<'py>,
+        tag: &Bound<'py, PyAny>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> Bound<'py, PyAny> {
+        if let Some(ref aliases) = self.aliases {
+            if let Ok(Some((_, choice_tag))) = aliases.validate(py, tag) {
+                return choice_tag.bind(py).clone();
+            }
+        }
+        if let (Some(normalized_tags), Ok(tag_str)) = (&self.normalized_tags, tag.downcast::<PyString>()) {
+            if let Ok(tag_str) = tag_str.to_str() {
+                let normalized = PyString::new(py, &self.tag_matching.normalize(tag_str));
+                if let Ok(Some((_, choice_tag))) = normalized_tags.validate(py, normalized.as_any()) {
+                    return choice_tag.bind(py).clone();
+                }
+            }
+        }
+        if let Some(lax_tags) = self.lax_tags.as_ref().filter(|_| !self.strict(state)) {
+            if let Ok(Some((_, choice_tag))) = lax_tags.validate(py, tag) {
+                state.floor_exactness(Exactness::Lax);
+                state.add_coercion_cost(1);
+                return choice_tag.bind(py).clone();
+            }
+        }
+        // literal tags are the fast path, predicates are only tried when they don't match
+        if !self.tag_predicates.is_empty() && !matches!(self.lookup.validate(py, tag), Ok(Some(_))) {
+            let strict = self.strict(state);
+            for predicate in &self.tag_predicates {
+                if let Some(exactness) = predicate.matches(tag, strict) {
+                    state.floor_exactness(exactness);
+                    if exactness == Exactness::Lax {
+                        state.add_coercion_cost(1);
+                    }
+                    return predicate.choice_tag.bind(py).clone();
+                }
+            }
+        }
+        tag.clone()
+    }
+
+    /// The union's own strictness, or the parent's when it isn't set, as `UnionValidator` does.
+    fn strict(&self, state: &ValidationState<'_, '_>) -> bool {
+        self.strict.unwrap_or(state.strict)
+    }
+
+    fn validate_fallback<'py>(
+        &self,
+        py: Python<'py>,
+        fallback: &TagFallback,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        match fallback {
+            TagFallback::Validator(validator) => validator.validate(py, input, state),
+            TagFallback::Smart => self.validate_smart_fallback(py, input, state),
+        }
+    }
+
+    /// Smart union matching over every choice, ranked the same way as `UnionValidator` in smart mode
+    /// whatever the config's `union_mode`.
+    fn validate_smart_fallback<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let mut errors: Vec<ValLineError> = Vec::new();
+        let mut best_match = BestMatch::new(state);
+
+        for (index, validator) in self.lookup.values.iter().enumerate() {
+            best_match.start_choice(state);
+            match validator.validate(py, input, state) {
+                Ok(new_success) if BestMatch::is_exact(state) => {
+                    best_match.restore(state);
+                    return Ok(new_success);
+                }
+                // the earlier choice wins ties
+                Ok(new_success) => best_match.push(state, new_success, index, |_| false),
+                Err(ValError::LineErrors(lines)) => {
+                    if !best_match.is_found() {
+                        let name = validator.get_name();
+                        errors.extend(lines.into_iter().map(|err| err.with_outer_location(name)));
+                    }
+                }
+                Err(err) => {
+                    best_match.restore(state);
+                    return Err(err);
+                }
+            }
+        }
+
+        if let Some((best_match, _, _)) = best_match.finish(state) {
+            return Ok(best_match);
+        }
+        Err(match self.custom_error {
+            Some(ref custom_error) => custom_error.as_val_error(input),
+            None => ValError::LineErrors(errors),
+        })
+    }
+
+    fn tag_not_found<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        if let Some(fallback) = &self.fallback {
+            return self.validate_fallback(py, fallback, input, state);
+        }
+        Err(match self.custom_error {
             Some(ref custom_error) => custom_error.as_val_error(input),
             None => ValError::new(
                 ErrorType::UnionTagNotFound {
@@ -427,6 +1945,6 @@ impl TaggedUnionValidator {
                 },
                 input,
             ),
-        }
+        })
     }
 }